@@ -0,0 +1,111 @@
+//! Scrambling of real inputs into look-alikes that can be shared: each day
+//! renames, reorders, mirrors or shifts what its answers do not depend on,
+//! so that the scrambled input has the same size and shape and the same
+//! answers as the real one.
+
+use crate::{
+    config,
+    runner::{self, Day},
+};
+use std::path::PathBuf;
+
+/// A small splitmix64 generator, seeded so that a scrambling can be
+/// repeated.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot draw below 0");
+        self.next_u64() % n
+    }
+
+    /// A number in `low..=high`.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        match (high - low).checked_add(1) {
+            Some(n) => low + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn coin(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+
+    /// A random order of `0..n`.
+    pub fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut order);
+        order
+    }
+}
+
+/// Scrambles an input of a day.
+pub type Anonymize = fn(&str, &mut Rng) -> String;
+
+/// The answers of a day on an input, or why it could not solve it.
+pub fn answers<I>(day: &Day<I>, input: &'static str) -> Result<[String; 2], String> {
+    runner::catch(|| {
+        let parsed = (day.parse)(input);
+        [
+            (day.part1)(&parsed),
+            day.part2
+                .map_or_else(|| "-".to_string(), |part2| part2(&parsed)),
+        ]
+    })
+}
+
+/// Where a scrambled input goes by default: among the fixtures of the day,
+/// named after the seed.
+pub fn fixture_path(day: u8, seed: u64) -> PathBuf {
+    config::current()
+        .fixtures_dir()
+        .join(format!("day{day:02}"))
+        .join(format!("anonymized-{seed}.txt"))
+}
+
+/// Applies one of the eight symmetries of the square to a rectangular grid:
+/// transposes it, then reverses the order of its rows and of its columns.
+pub fn reorient(
+    rows: &[&str],
+    transpose: bool,
+    flip_rows: bool,
+    flip_columns: bool,
+) -> Vec<String> {
+    let mut cells: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+    if transpose {
+        let width = cells.first().map_or(0, Vec::len);
+        cells = (0..width)
+            .map(|j| cells.iter().map(|row| row[j]).collect())
+            .collect();
+    }
+    if flip_rows {
+        cells.reverse();
+    }
+    if flip_columns {
+        cells.iter_mut().for_each(|row| row.reverse());
+    }
+    cells.into_iter().map(String::from_iter).collect()
+}
@@ -0,0 +1,127 @@
+//! An audit of determinism: a day is solved many times over, in ways that
+//! change the seeds of the standard hashers, and every answer must come out
+//! the same. A `HashSet` or `HashMap` walked in iteration order shows up
+//! here as differing answers. An order that follows the input alone, such
+//! as ties a heap breaks by what it was given first, does not, since the
+//! input stays the same from run to run.
+//!
+//! The seeds of `RandomState` are drawn once per thread and then stepped for
+//! every new map, so repeated runs, runs on fresh threads and runs in fresh
+//! processes all hash differently.
+
+use crate::{
+    budget::{self, Verdict},
+    input::Trim,
+    runner::{self, Day, Part},
+};
+use std::{collections::BTreeMap, env, path::Path, thread};
+
+/// The answers of both parts in one run, with how the run was made.
+struct Outcome {
+    run: String,
+    answers: [String; 2],
+}
+
+fn solve<I>(
+    parse: fn(&'static str) -> I,
+    parts: [Option<Part<I>>; 2],
+    input: &'static str,
+) -> [String; 2] {
+    let answer = |result: Result<String, String>| match result {
+        Ok(answer) => answer,
+        Err(err) => format!("panic: {err}"),
+    };
+    match runner::catch(|| parse(input)) {
+        Ok(parsed) => parts.map(|part| match part {
+            Some(part) => answer(runner::catch(|| part(&parsed))),
+            None => "-".to_string(),
+        }),
+        Err(err) => [format!("parse panic: {err}"), "-".to_string()],
+    }
+}
+
+/// Solves the input at `path` `runs` times in each configuration, then
+/// reports the parts whose answers differ. Returns whether every answer
+/// agreed.
+pub fn audit<I>(day: &Day<I>, path: &Path, input: &'static str, runs: usize) -> bool {
+    let parse = day.parse;
+    let parts = [Some(day.part1), day.part2];
+    let mut outcomes = Vec::new();
+
+    for run in 1..=runs {
+        outcomes.push(Outcome {
+            run: format!("repeated #{run}"),
+            answers: solve(parse, parts, input),
+        });
+    }
+    for threads in [2, 8] {
+        let answers: Vec<[String; 2]> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| scope.spawn(move || solve(parse, parts, input)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("the runs catch their panics"))
+                .collect()
+        });
+        outcomes.extend(
+            answers
+                .into_iter()
+                .zip(1..)
+                .map(|(answers, thread)| Outcome {
+                    run: format!("{threads} threads #{thread}"),
+                    answers,
+                }),
+        );
+    }
+    let exe = env::current_exe().expect("cannot locate the running executable");
+    for process in 1..=runs.min(4) {
+        let answers = [1, 2].map(
+            |part| match budget::run_part(&exe, part, Some(path), None) {
+                Verdict::Solved(answer, _) => answer,
+                Verdict::Skipped => "-".to_string(),
+                Verdict::Failed(err) => format!("panic: {err}"),
+                Verdict::Timeout(_) => "timeout".to_string(),
+            },
+        );
+        outcomes.push(Outcome {
+            run: format!("process #{process}"),
+            answers,
+        });
+    }
+    if let Some(stream) = day.stream {
+        let answers = match stream(&mut input.as_bytes(), Trim::None, None) {
+            Ok(answers) => answers,
+            Err(err) => [format!("error: {err}"), format!("error: {err}")],
+        };
+        outcomes.push(Outcome {
+            run: "streamed".to_string(),
+            answers,
+        });
+    }
+
+    let mut deterministic = true;
+    for part in 1..=2 {
+        let mut answers: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for outcome in &outcomes {
+            answers
+                .entry(&outcome.answers[part - 1])
+                .or_default()
+                .push(&outcome.run);
+        }
+        match answers.len() {
+            1 => {
+                let (answer, runs) = answers.first_key_value().expect("one answer");
+                println!("part {part}: {answer} in all {} runs", runs.len());
+            }
+            count => {
+                deterministic = false;
+                println!("part {part}: {count} different answers");
+                for (answer, runs) in &answers {
+                    println!("  {answer}  from {}", runs.join(", "));
+                }
+            }
+        }
+    }
+    deterministic
+}
@@ -0,0 +1,192 @@
+use aoc_2025::{
+    budget::{self, Verdict},
+    config::{self, Config},
+    dashboard::Dashboard,
+    perf::{self, Baseline},
+    runner::{self, day_exe, print_table},
+    watch::Watch,
+};
+use std::{
+    env,
+    net::TcpListener,
+    process::{self, Command},
+};
+
+const DAYS: u8 = 12;
+
+fn usage() -> ! {
+    eprintln!("usage: aoc run all|DAY [--budget SECONDS]");
+    eprintln!("       aoc perf-report [--against best|previous] [--threshold PERCENT]");
+    eprintln!("       aoc encrypt|decrypt all|DAY");
+    eprintln!("       aoc serve [--port PORT] [--budget SECONDS]");
+    eprintln!("       aoc watch DAY [--budget SECONDS]");
+    eprintln!(
+        "       aoc repl|batch|check-input|stream|bench|shrink|anonymize|audit|compare|report DAY [ARGS...]"
+    );
+    eprintln!("every command takes [--config FILE] [--data-dir DIR] [--set KEY=VALUE]...");
+    process::exit(2)
+}
+
+fn parse_days(days: &str) -> Vec<u8> {
+    match days {
+        "all" => (1..=DAYS).collect(),
+        day => match day.parse() {
+            Ok(day) if (1..=DAYS).contains(&day) => vec![day],
+            _ => usage(),
+        },
+    }
+}
+
+/// Hands a command over to the binary of a single day.
+fn forward(command: &str, day: &str, args: &[&str]) {
+    let [day] = parse_days(day)[..] else { usage() };
+    let exe = day_exe(day);
+    match Command::new(&exe)
+        .arg(command)
+        .args(args)
+        .args(config::current().arguments())
+        .status()
+    {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(err) => {
+            eprintln!("cannot start {}: {err}", exe.display());
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &[&str]) {
+    let mut args = args.to_vec();
+    let budget = runner::take_option(&mut args, "--budget")
+        .map(|seconds| budget::parse_budget(seconds).unwrap_or_else(|| usage()));
+    let [days] = args[..] else { usage() };
+
+    let mut rows = Vec::new();
+    let mut failures = 0;
+    for day in parse_days(days) {
+        let exe = day_exe(day);
+        let parts = if exe.exists() {
+            [1, 2].map(|part| budget::run_part(&exe, part, None, budget))
+        } else {
+            let err = format!("{} is missing, build it first", exe.display());
+            [Verdict::Failed(err), Verdict::Skipped]
+        };
+        let problems: Vec<String> = parts
+            .iter()
+            .zip(1..)
+            .filter_map(|(verdict, part)| match verdict {
+                Verdict::Failed(err) => Some(format!("part {part} failed: {err}")),
+                Verdict::Timeout(budget) => Some(format!("part {part} exceeded {budget:.2?}")),
+                Verdict::Skipped | Verdict::Solved(..) => None,
+            })
+            .collect();
+        if !problems.is_empty() {
+            failures += 1;
+        }
+        rows.push(vec![
+            format!("{day:02}"),
+            parts[0].answer().to_string(),
+            parts[0].time(),
+            parts[1].answer().to_string(),
+            parts[1].time(),
+            if problems.is_empty() {
+                "ok".to_string()
+            } else {
+                problems.join("; ").replace('\n', " ")
+            },
+        ]);
+    }
+    print_table(
+        &["day", "part 1", "time 1", "part 2", "time 2", "status"],
+        &rows,
+    );
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn perf_report(args: &[&str]) {
+    let mut baseline = Baseline::Best;
+    let mut threshold = 10.0;
+    for option in args.chunks(2) {
+        match option {
+            ["--against", "best"] => baseline = Baseline::Best,
+            ["--against", "previous"] => baseline = Baseline::Previous,
+            ["--threshold", percent] => match percent.parse() {
+                Ok(percent) => threshold = percent,
+                Err(_) => usage(),
+            },
+            _ => usage(),
+        }
+    }
+
+    let records = match perf::load(&perf::history_path()) {
+        Ok(records) => records,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let regressions = perf::report(&records, baseline, threshold);
+    if regressions > 0 {
+        println!("{regressions} stage(s) slowed down by more than {threshold}%");
+        process::exit(1);
+    }
+}
+
+fn serve(args: &[&str]) {
+    let mut args = args.to_vec();
+    let budget = runner::take_option(&mut args, "--budget")
+        .map(|seconds| budget::parse_budget(seconds).unwrap_or_else(|| usage()));
+    let port = runner::take_option(&mut args, "--port")
+        .map_or(Some(2025), |port| port.parse().ok())
+        .unwrap_or_else(|| usage());
+    if !args.is_empty() {
+        usage();
+    }
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("cannot listen on port {port}: {err}");
+            process::exit(1);
+        }
+    };
+    println!("serving the dashboard on http://127.0.0.1:{port}/");
+    Dashboard::new(DAYS, budget).serve(listener);
+}
+
+fn watch(args: &[&str]) {
+    let mut args = args.to_vec();
+    let budget = runner::take_option(&mut args, "--budget")
+        .map(|seconds| budget::parse_budget(seconds).unwrap_or_else(|| usage()));
+    let [day] = args[..] else { usage() };
+    let [day] = parse_days(day)[..] else { usage() };
+    Watch::new(day, budget).watch()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if let Err(err) = Config::from_args(&mut args).and_then(config::install) {
+        eprintln!("{err}");
+        process::exit(2);
+    }
+    match args.as_slice() {
+        ["run", rest @ ..] => run(rest),
+        ["perf-report", rest @ ..] => perf_report(rest),
+        ["serve", rest @ ..] => serve(rest),
+        ["watch", rest @ ..] => watch(rest),
+        [command @ ("encrypt" | "decrypt"), days] => {
+            for day in parse_days(days) {
+                runner::seal(&runner::default_input_path(day), *command == "encrypt");
+            }
+        }
+        [
+            command @ ("repl" | "batch" | "check-input" | "stream" | "bench" | "shrink" | "audit"
+            | "anonymize" | "compare" | "report"),
+            day,
+            rest @ ..,
+        ] => forward(command, day, rest),
+        _ => usage(),
+    }
+}
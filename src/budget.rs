@@ -0,0 +1,126 @@
+use crate::config;
+use std::{
+    io::Read,
+    path::Path,
+    process::{Command, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// The exit code of the `part` command of a day that has no such part.
+pub const MISSING_PART: i32 = 3;
+
+/// How a single part of a puzzle ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Skipped,
+    Solved(String, Duration),
+    Failed(String),
+    Timeout(Duration),
+}
+
+impl Verdict {
+    pub fn answer(&self) -> &str {
+        match self {
+            Verdict::Skipped => "-",
+            Verdict::Solved(answer, _) => answer,
+            Verdict::Failed(_) => "ERROR",
+            Verdict::Timeout(_) => "TIMEOUT",
+        }
+    }
+
+    pub fn time(&self) -> String {
+        match self {
+            Verdict::Solved(_, elapsed) | Verdict::Timeout(elapsed) => format!("{elapsed:.2?}"),
+            Verdict::Skipped | Verdict::Failed(_) => "-".to_string(),
+        }
+    }
+}
+
+pub fn parse_budget(seconds: &str) -> Option<Duration> {
+    seconds
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|budget| !budget.is_zero())
+}
+
+/// Reads a pipe of a child to its end on a thread of its own.
+fn drain(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Solves one part in a child process running the `part` command of the
+/// day binary `exe`, killing it once it exceeds `budget`. A part that runs
+/// away therefore cannot take its caller down with it. The child is handed
+/// the configuration options of this process.
+pub fn run_part(exe: &Path, part: u8, input: Option<&Path>, budget: Option<Duration>) -> Verdict {
+    let mut command = Command::new(exe);
+    command.arg("part").arg(part.to_string());
+    if let Some(input) = input {
+        command.arg(input);
+    }
+    command.args(config::current().arguments());
+    let mut child = match command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => return Verdict::Failed(format!("cannot start {}: {err}", exe.display())),
+    };
+    // Drain the pipes as the child writes, or a child writing more than they
+    // hold would block forever.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => match budget {
+                Some(budget) if start.elapsed() > budget => {
+                    // The child may have exited in the meantime, in which
+                    // case there is nothing left to kill.
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Verdict::Timeout(budget);
+                }
+                _ => thread::sleep(Duration::from_millis(5)),
+            },
+            Err(err) => return Verdict::Failed(err.to_string()),
+        }
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if status.code() == Some(MISSING_PART) {
+        return Verdict::Skipped;
+    }
+    if !status.success() {
+        let message = stderr.trim();
+        return Verdict::Failed(if message.is_empty() {
+            status.to_string()
+        } else {
+            message.to_string()
+        });
+    }
+    let mut lines = stdout.lines();
+    match (
+        lines.next(),
+        lines.next().and_then(|nanos| nanos.parse().ok()),
+    ) {
+        (Some(answer), Some(nanos)) => {
+            Verdict::Solved(answer.to_string(), Duration::from_nanos(nanos))
+        }
+        _ => Verdict::Failed(format!("unexpected output from {}", exe.display())),
+    }
+}
@@ -0,0 +1,553 @@
+//! The project configuration: where the inputs live and the parameters of
+//! the puzzles, read from `aoc.toml` and overridden from the command line.
+//!
+//! The file uses a small subset of TOML: `[tables]`, `key = value` pairs and
+//! `#` comments, where a value is an integer, a boolean, a quoted string or a
+//! single-line array of those.
+//!
+//! ```toml
+//! [data]
+//! dir = "inputs"
+//!
+//! [day08]
+//! links = 10
+//! ```
+//!
+//! Each day reads the keys of its own table, `day01` to `day12`; a missing
+//! key leaves the puzzle's own value in place. The keys and their values are
+//! checked as the configuration is installed, so that a misspelled key or a
+//! value the puzzle cannot take is reported before anything runs.
+
+use crate::{day01, day03, day04, day08, day11, runner::take_option};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Scalar(String),
+    List(Vec<String>),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    values: BTreeMap<String, Value>,
+    /// The command-line options the configuration was built from, to hand
+    /// over to child processes.
+    arguments: Vec<String>,
+}
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+}
+
+fn parse_string(text: &str) -> Option<(String, &str)> {
+    let mut chars = text.strip_prefix('"')?.char_indices();
+    let mut string = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((string, &text[i + 2..])),
+            '\\' => string.push(match chars.next()?.1 {
+                'n' => '\n',
+                't' => '\t',
+                c @ ('"' | '\\') => c,
+                _ => return None,
+            }),
+            c => string.push(c),
+        }
+    }
+    None
+}
+
+/// Parses a scalar at the start of `text`, returning it with the rest.
+fn parse_scalar(text: &str) -> Option<(String, &str)> {
+    if text.starts_with('"') {
+        return parse_string(text);
+    }
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || "+-_.".contains(c)))
+        .unwrap_or(text.len());
+    let word = &text[..end];
+    let integer = word.strip_prefix(['+', '-']).unwrap_or(word);
+    if word == "true" || word == "false" {
+        Some((word.to_string(), &text[end..]))
+    } else if !integer.is_empty() && integer.bytes().all(|b| b.is_ascii_digit() || b == b'_') {
+        Some((word.replace(['_', '+'], ""), &text[end..]))
+    } else {
+        None
+    }
+}
+
+fn parse_value(text: &str) -> Option<Value> {
+    let Some(mut rest) = text.strip_prefix('[') else {
+        let (scalar, rest) = parse_scalar(text)?;
+        return rest.trim().is_empty().then_some(Value::Scalar(scalar));
+    };
+    let mut items = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if let Some(rest) = rest.strip_prefix(']') {
+            return rest.trim().is_empty().then_some(Value::List(items));
+        }
+        let (item, after) = parse_scalar(rest)?;
+        items.push(item);
+        rest = after.trim_start();
+        if let Some(after) = rest.strip_prefix(',') {
+            rest = after;
+        } else if !rest.starts_with(']') {
+            return None;
+        }
+    }
+}
+
+/// Drops a comment, leaving the `#` inside strings alone.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+        let mut table = String::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            let error = |message: &str| format!("line {}: {message}", number + 1);
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[') {
+                match name.strip_suffix(']').map(str::trim) {
+                    Some(name) if is_key(name) => table = name.to_string(),
+                    _ => return Err(error("invalid table header")),
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(error("expected `key = value`"));
+            };
+            let key = key.trim();
+            if !is_key(key) {
+                return Err(error(&format!("invalid key `{key}`")));
+            }
+            let key = if table.is_empty() {
+                key.to_string()
+            } else {
+                format!("{table}.{key}")
+            };
+            let value = parse_value(value.trim())
+                .ok_or_else(|| error(&format!("invalid value of {key}")))?;
+            if config.values.insert(key.clone(), value).is_some() {
+                return Err(error(&format!("{key} is set twice")));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Reads a configuration file, resolving its data directories against
+    /// the directory of the file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+        let mut config =
+            Config::parse(&text).map_err(|err| format!("{}: {err}", path.display()))?;
+        let base = path.parent().unwrap_or(Path::new(""));
+        for key in ["data.dir", "data.store", "data.fixtures"] {
+            if let Some(Value::Scalar(dir)) = config.values.get_mut(key) {
+                *dir = base.join(&*dir).to_string_lossy().into_owned();
+            }
+        }
+        Ok(config)
+    }
+
+    /// Applies an override such as `day08.links=10`, whose value is written
+    /// as in the file, except that a bare word stands for a string.
+    pub fn set(&mut self, assignment: &str) -> Result<(), String> {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("expected KEY=VALUE, got {assignment}"))?;
+        let key = key.trim();
+        if key.split('.').any(|part| !is_key(part)) {
+            return Err(format!("invalid key `{key}`"));
+        }
+        let value = value.trim();
+        let value = parse_value(value).unwrap_or_else(|| Value::Scalar(value.to_string()));
+        self.values.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    /// Reads the `--config FILE`, `--data-dir DIR` and `--set KEY=VALUE`
+    /// options out of `args`. Without `--config`, `aoc.toml` is read if it
+    /// exists.
+    pub fn from_args(args: &mut Vec<&str>) -> Result<Self, String> {
+        let mut arguments = Vec::new();
+        let mut config = match take_option(args, "--config") {
+            Some("") => return Err("--config needs a file".to_string()),
+            Some(path) => {
+                arguments.extend(["--config".to_string(), path.to_string()]);
+                Config::load(Path::new(path))?
+            }
+            None if default_path().exists() => Config::load(&default_path())?,
+            None => Config::default(),
+        };
+        if let Some(dir) = take_option(args, "--data-dir") {
+            if dir.is_empty() {
+                return Err("--data-dir needs a directory".to_string());
+            }
+            arguments.extend(["--data-dir".to_string(), dir.to_string()]);
+            config
+                .values
+                .insert("data.dir".to_string(), Value::Scalar(dir.to_string()));
+        }
+        while let Some(assignment) = take_option(args, "--set") {
+            config.set(assignment)?;
+            arguments.extend(["--set".to_string(), assignment.to_string()]);
+        }
+        config.arguments = arguments;
+        Ok(config)
+    }
+
+    /// The options reproducing this configuration in another process.
+    pub fn arguments(&self) -> &[String] {
+        &self.arguments
+    }
+
+    /// The directory holding the `dayNN.txt` inputs.
+    pub fn data_dir(&self) -> PathBuf {
+        match self.values.get("data.dir") {
+            Some(Value::Scalar(dir)) => PathBuf::from(dir),
+            _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+        }
+    }
+
+    /// The directory holding the sealed copies of the inputs.
+    pub fn store_dir(&self) -> PathBuf {
+        match self.values.get("data.store") {
+            Some(Value::Scalar(dir)) => PathBuf::from(dir),
+            _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+        }
+    }
+
+    /// The directory holding the shrunk failing cases, one directory per day.
+    pub fn fixtures_dir(&self) -> PathBuf {
+        match self.values.get("data.fixtures") {
+            Some(Value::Scalar(dir)) => PathBuf::from(dir),
+            _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures"),
+        }
+    }
+
+    /// The value of `key` in the table of `day`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is a list or does not parse as a `T`, which
+    /// [`install`] rules out for the keys it knows.
+    pub fn param<T: FromStr<Err: Display>>(&self, day: u8, key: &str) -> Option<T> {
+        self.try_param(day, key)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The list under `key` in the table of `day`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is not a list of `T`, which [`install`] rules out
+    /// for the keys it knows.
+    pub fn list<T: FromStr<Err: Display>>(&self, day: u8, key: &str) -> Option<Vec<T>> {
+        self.try_list(day, key)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The value of `key` in the table of `day`, or why it is no `T`.
+    pub fn try_param<T: FromStr<Err: Display>>(
+        &self,
+        day: u8,
+        key: &str,
+    ) -> Result<Option<T>, String> {
+        let key = format!("day{day:02}.{key}");
+        match self.values.get(&key) {
+            None => Ok(None),
+            Some(Value::Scalar(value)) => parse(&key, value).map(Some),
+            Some(Value::List(_)) => Err(format!("{key} must not be a list")),
+        }
+    }
+
+    /// The list under `key` in the table of `day`, or why it is no list of
+    /// `T`.
+    pub fn try_list<T: FromStr<Err: Display>>(
+        &self,
+        day: u8,
+        key: &str,
+    ) -> Result<Option<Vec<T>>, String> {
+        let key = format!("day{day:02}.{key}");
+        match self.values.get(&key) {
+            None => Ok(None),
+            Some(Value::List(values)) => values
+                .iter()
+                .map(|value| parse(&key, value))
+                .collect::<Result<_, _>>()
+                .map(Some),
+            Some(Value::Scalar(_)) => Err(format!("{key} must be a list")),
+        }
+    }
+
+    /// Checks that every key belongs to a table that takes it, and that the
+    /// values of each table are ones the puzzle can take.
+    pub fn check(&self) -> Result<(), String> {
+        for (key, value) in &self.values {
+            let Some((table, name)) = key.split_once('.') else {
+                return Err(format!("unknown key {key}: keys belong in a table"));
+            };
+            let Some(schema) = schema(table) else {
+                return Err(format!("unknown key {key}: there is no [{table}] table"));
+            };
+            if !schema.keys.contains(&name) {
+                return Err(format!(
+                    "unknown key {key}: the [{table}] table takes {}",
+                    schema.keys.join(", ")
+                ));
+            }
+            match (value, schema.lists.contains(&name)) {
+                (Value::List(_), false) => return Err(format!("{key} must not be a list")),
+                (Value::Scalar(_), true) => return Err(format!("{key} must be a list")),
+                _ => {}
+            }
+        }
+        let tables: BTreeSet<&str> = self
+            .values
+            .keys()
+            .filter_map(|key| key.split_once('.'))
+            .map(|(table, _)| table)
+            .collect();
+        tables
+            .into_iter()
+            .filter_map(schema)
+            .try_for_each(|schema| (schema.check)(self))
+    }
+}
+
+/// The keys of a table, those of them that take a list, and a check of
+/// their values.
+struct Schema {
+    keys: &'static [&'static str],
+    lists: &'static [&'static str],
+    check: fn(&Config) -> Result<(), String>,
+}
+
+/// The schema of each table: the data directories, and the parameters of
+/// each day, any of which may also name a strategy.
+fn schema(table: &str) -> Option<Schema> {
+    let schema = |keys, lists, check| Schema { keys, lists, check };
+    Some(match table {
+        "data" => schema(&["dir", "store", "fixtures"], &[], |_| Ok(())),
+        "day01" => schema(
+            &["size", "start", "gears", "strategy"],
+            &["gears"],
+            |config| day01::Lock::from_config(config).map(drop),
+        ),
+        "day03" => schema(&["digits", "strategy"], &["digits"], |config| {
+            day03::Params::from_config(config).map(drop)
+        }),
+        "day04" => schema(&["threshold", "strategy"], &[], |config| {
+            day04::Params::from_config(config).map(drop)
+        }),
+        "day08" => schema(&["links", "top", "strategy"], &[], |config| {
+            day08::Params::from_config(config).map(drop)
+        }),
+        "day11" => schema(
+            &["part1_from", "part2_from", "to", "via", "strategy"],
+            &["via"],
+            |config| day11::Params::from_config(config).map(drop),
+        ),
+        "day02" | "day05" | "day06" | "day07" | "day09" | "day10" | "day12" => {
+            schema(&["strategy"], &[], |_| Ok(()))
+        }
+        _ => return None,
+    })
+}
+
+fn parse<T: FromStr<Err: Display>>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|err| format!("invalid value of {key}: {value} ({err})"))
+}
+
+static CURRENT: OnceLock<Config> = OnceLock::new();
+
+/// Makes `config` the configuration of the process once [`Config::check`]
+/// finds nothing wrong with it, which can only be done before it is first
+/// read.
+pub fn install(config: Config) -> Result<(), String> {
+    config.check()?;
+    CURRENT
+        .set(config)
+        .map_err(|_| "the configuration is already in use".to_string())
+}
+
+/// The configuration of the process, empty unless one was installed.
+pub fn current() -> &'static Config {
+    CURRENT.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(value: &str) -> Value {
+        Value::Scalar(value.to_string())
+    }
+
+    #[test]
+    fn parses_values_tables_and_comments() {
+        let config = Config::parse(
+            "# inputs\n\
+             [data]\n\
+             dir = \"in # puts\\\\\"  # a comment\n\
+             \n\
+             [ day08 ]\n\
+             links = +1_000\n\
+             top = -3\n\
+             [day11]\n\
+             via = [\"dac\", \"fft\", ]\n\
+             strategy = true\n",
+        )
+        .unwrap();
+        let values: Vec<(&str, &Value)> = config.values.iter().map(|(k, v)| (&**k, v)).collect();
+        assert_eq!(
+            values,
+            [
+                ("data.dir", &scalar("in # puts\\")),
+                ("day08.links", &scalar("1000")),
+                ("day08.top", &scalar("-3")),
+                ("day11.strategy", &scalar("true")),
+                ("day11.via", &Value::List(vec!["dac".into(), "fft".into()])),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors_name_the_line() {
+        for (text, message) in [
+            ("[day08\n", "line 1: invalid table header"),
+            ("[]\n", "line 1: invalid table header"),
+            ("\nlinks\n", "line 2: expected `key = value`"),
+            ("day 08 = 1\n", "line 1: invalid key `day 08`"),
+            (
+                "[day08]\nlinks = ten\n",
+                "line 2: invalid value of day08.links",
+            ),
+            (
+                "[day08]\nlinks = \"10\n",
+                "line 2: invalid value of day08.links",
+            ),
+            (
+                "[day08]\nlinks = [1, 2\n",
+                "line 2: invalid value of day08.links",
+            ),
+            (
+                "[day08]\nlinks = 1 2\n",
+                "line 2: invalid value of day08.links",
+            ),
+            (
+                "[day08]\nlinks = 1\nlinks = 2\n",
+                "line 3: day08.links is set twice",
+            ),
+        ] {
+            assert_eq!(Config::parse(text), Err(message.to_string()), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn set_overrides_with_bare_words() {
+        let mut config = Config::parse("[day11]\nto = \"out\"\n").unwrap();
+        config.set("day11.to=svr").unwrap();
+        config.set(r#" day11.via = ["dac", "fft"] "#).unwrap();
+        assert_eq!(config.try_param::<String>(11, "to"), Ok(Some("svr".into())));
+        assert_eq!(
+            config.try_list::<String>(11, "via"),
+            Ok(Some(vec!["dac".into(), "fft".into()]))
+        );
+        assert_eq!(
+            config.set("day11.to"),
+            Err("expected KEY=VALUE, got day11.to".to_string())
+        );
+        assert_eq!(
+            config.set("day11..to=out"),
+            Err("invalid key `day11..to`".to_string())
+        );
+    }
+
+    #[test]
+    fn typed_lookups_report_the_key() {
+        let config = Config::parse("[day03]\ndigits = [2, \"x\"]\n[day08]\nlinks = 10\n").unwrap();
+        assert_eq!(config.try_param::<usize>(8, "links"), Ok(Some(10)));
+        assert_eq!(config.try_param::<usize>(8, "top"), Ok(None));
+        assert_eq!(
+            config.try_list::<usize>(8, "links"),
+            Err("day08.links must be a list".to_string())
+        );
+        assert!(
+            config
+                .try_list::<usize>(3, "digits")
+                .unwrap_err()
+                .starts_with("invalid value of day03.digits: x (")
+        );
+    }
+
+    #[test]
+    fn check_rejects_unknown_keys_and_shapes() {
+        assert_eq!(
+            Config::parse("[day08]\nlinks = 10\n").unwrap().check(),
+            Ok(())
+        );
+        for (text, message) in [
+            ("links = 10\n", "unknown key links: keys belong in a table"),
+            (
+                "[day13]\nlinks = 10\n",
+                "unknown key day13.links: there is no [day13] table",
+            ),
+            (
+                "[day08]\nlink = 10\n",
+                "unknown key day08.link: the [day08] table takes links, top, strategy",
+            ),
+            ("[day08]\nlinks = [10]\n", "day08.links must not be a list"),
+            ("[day11]\nvia = \"dac\"\n", "day11.via must be a list"),
+            (
+                "[day03]\ndigits = [2, 20]\n",
+                "day03.digits must be between 1 and 19, the most digits a joltage fits in, not 20",
+            ),
+        ] {
+            let config = Config::parse(text).unwrap();
+            assert_eq!(config.check(), Err(message.to_string()), "{text:?}");
+        }
+        let config = Config::parse("[day08]\nlinks = -1\n").unwrap();
+        assert!(
+            config
+                .check()
+                .unwrap_err()
+                .starts_with("invalid value of day08.links: -1")
+        );
+    }
+}
@@ -0,0 +1,554 @@
+//! A dashboard served on localhost: a page per day with its answers, their
+//! verification against the recorded ones, the timing history and a
+//! drawing of the input. Pages are self-contained, with inline styles and
+//! SVG, so that it works offline.
+
+use crate::{
+    budget::{self, Verdict},
+    day08, day09, day12,
+    perf::{self, Record},
+    runner::{self, Day},
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write as _,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::Path,
+    time::Duration,
+};
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em auto;max-width:60em;color:#222}\
+table{border-collapse:collapse}td,th{padding:.2em .8em;border-bottom:1px solid #ddd;text-align:left}\
+.ok{color:#070}.bad{color:#b00}.muted{color:#888}svg{border:1px solid #ddd;background:#fcfcfc}\
+nav a{margin-right:.6em}form{display:inline}";
+
+/// The side of the square the drawings are scaled into.
+const SIZE: f64 = 600.0;
+
+/// The most a request may send besides its body, and the largest body,
+/// which is plenty for the forms of the pages.
+const MAX_HEAD: u64 = 16 * 1024;
+const MAX_BODY: usize = 4 * 1024;
+/// How long a client may leave the server, which answers one request at a
+/// time, waiting.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+const PALETTE: [&str; 6] = [
+    "#d62728", "#1f77b4", "#2ca02c", "#ff7f0e", "#9467bd", "#8c564b",
+];
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The latest answers of a day, and how they compare to the recorded ones.
+struct Run {
+    parts: [Verdict; 2],
+    checks: [Check; 2],
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Check {
+    Verified,
+    Mismatch,
+    Unverified,
+}
+
+pub struct Dashboard {
+    days: u8,
+    budget: Option<Duration>,
+    runs: BTreeMap<u8, Run>,
+}
+
+impl Dashboard {
+    pub fn new(days: u8, budget: Option<Duration>) -> Self {
+        Self {
+            days,
+            budget,
+            runs: BTreeMap::new(),
+        }
+    }
+
+    /// Solves both parts of `day` in child processes, like `aoc run` does.
+    fn run(&mut self, day: u8) {
+        let exe = runner::day_exe(day);
+        let parts = if exe.exists() {
+            [1, 2].map(|part| budget::run_part(&exe, part, None, self.budget))
+        } else {
+            let err = format!("{} is missing, build it first", exe.display());
+            [Verdict::Failed(err), Verdict::Skipped]
+        };
+        let expected = runner::read_expected(&runner::default_input_path(day));
+        let checks = [0, 1].map(|index| {
+            match (
+                &parts[index],
+                expected.as_ref().and_then(|e| e[index].as_ref()),
+            ) {
+                (Verdict::Solved(answer, _), Some(expected)) if answer == expected => {
+                    Check::Verified
+                }
+                (_, Some(_)) => Check::Mismatch,
+                (_, None) => Check::Unverified,
+            }
+        });
+        self.runs.insert(day, Run { parts, checks });
+    }
+
+    /// Serves the dashboard until the process is killed, one request at a
+    /// time.
+    pub fn serve(&mut self, listener: TcpListener) {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            let timeouts = stream
+                .set_read_timeout(Some(TIMEOUT))
+                .and_then(|_| stream.set_write_timeout(Some(TIMEOUT)));
+            if let Err(err) = timeouts
+                .map_err(|err| err.to_string())
+                .and_then(|_| self.respond(stream))
+            {
+                eprintln!("{err}");
+            }
+        }
+    }
+
+    fn respond(&mut self, stream: TcpStream) -> Result<(), String> {
+        let mut reader = BufReader::new((&stream).take(MAX_HEAD + MAX_BODY as u64));
+        let mut request = String::new();
+        reader
+            .read_line(&mut request)
+            .map_err(|err| err.to_string())?;
+        let (mut length, mut host, mut origin) = (0, None, None);
+        loop {
+            let mut header = String::new();
+            match reader.read_line(&mut header) {
+                Ok(0) | Err(_) => break,
+                Ok(_) if header.trim().is_empty() => break,
+                Ok(_) => {}
+            }
+            let Some((name, value)) = header.split_once(':') else {
+                continue;
+            };
+            let value = value.trim().to_string();
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => length = value.parse().unwrap_or(0),
+                "host" => host = Some(value),
+                "origin" => origin = Some(value),
+                _ => {}
+            }
+        }
+        // Only pages of the dashboard itself may make requests: a page of
+        // another site sends its own Origin, and one reaching the server
+        // through a rebound domain name sends that name as the Host.
+        let port = stream.local_addr().map_err(|err| err.to_string())?.port();
+        let hosts = [format!("127.0.0.1:{port}"), format!("localhost:{port}")];
+        let allowed = host.is_some_and(|host| hosts.contains(&host))
+            && origin
+                .is_none_or(|origin| hosts.iter().any(|host| origin == format!("http://{host}")));
+        if !allowed {
+            return reply(&stream, "403 Forbidden", "text/plain", "forbidden");
+        }
+        if length > MAX_BODY {
+            return reply(
+                &stream,
+                "413 Content Too Large",
+                "text/plain",
+                "request body too large",
+            );
+        }
+        let mut body = vec![0; length];
+        reader
+            .read_exact(&mut body)
+            .map_err(|err| err.to_string())?;
+
+        let words: Vec<&str> = request.split_whitespace().collect();
+        let (method, path) = match words[..] {
+            [method, path, ..] => (method, path),
+            _ => return reply(&stream, "400 Bad Request", "text/plain", "bad request"),
+        };
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let day = |number: &str| {
+            number
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=self.days).contains(day))
+        };
+        match (method, segments.as_slice()) {
+            ("GET", []) => reply(&stream, "200 OK", "text/html", &self.index()),
+            ("GET", ["day", number]) => match day(number) {
+                Some(day) => {
+                    if !self.runs.contains_key(&day) {
+                        self.run(day);
+                    }
+                    reply(&stream, "200 OK", "text/html", &self.day_page(day))
+                }
+                None => not_found(&stream),
+            },
+            ("POST", ["day", number, "run"]) => match day(number) {
+                Some(day) => {
+                    self.run(day);
+                    redirect(&stream, &format!("/day/{day:02}"))
+                }
+                None => not_found(&stream),
+            },
+            ("POST", ["run"]) => {
+                for day in 1..=self.days {
+                    self.run(day);
+                }
+                redirect(&stream, "/")
+            }
+            _ => not_found(&stream),
+        }
+    }
+
+    fn index(&self) -> String {
+        let mut html = String::from(
+            "<h1>Advent of Code 2025</h1>\
+             <form method=\"post\" action=\"/run\"><button>Run all days</button></form>\
+             <table><tr><th>day</th><th>part 1</th><th>part 2</th><th>status</th></tr>",
+        );
+        for day in 1..=self.days {
+            let (answers, status) = match self.runs.get(&day) {
+                Some(run) => (
+                    run.parts
+                        .iter()
+                        .map(|part| format!("<td>{}</td>", escape(part.answer())))
+                        .collect::<String>(),
+                    status(run),
+                ),
+                None => (
+                    "<td></td><td></td>".to_string(),
+                    "<span class=\"muted\">not run yet</span>".to_string(),
+                ),
+            };
+            let _ = write!(
+                html,
+                "<tr><td><a href=\"/day/{day:02}\">{day:02}</a></td>{answers}<td>{status}</td></tr>"
+            );
+        }
+        html.push_str("</table>");
+        page("Advent of Code 2025", &html)
+    }
+
+    fn day_page(&self, day: u8) -> String {
+        let mut html = String::from("<nav><a href=\"/\">all days</a>");
+        if day > 1 {
+            let _ = write!(html, "<a href=\"/day/{:02}\">previous</a>", day - 1);
+        }
+        if day < self.days {
+            let _ = write!(html, "<a href=\"/day/{:02}\">next</a>", day + 1);
+        }
+        let _ = write!(
+            html,
+            "</nav><h1>Day {day}</h1>\
+             <form method=\"post\" action=\"/day/{day:02}/run\"><button>Re-run</button></form>\
+             <h2>Answers</h2><table><tr><th>part</th><th>answer</th><th>time</th><th>check</th></tr>"
+        );
+        let run = &self.runs[&day];
+        for (index, (part, check)) in run.parts.iter().zip(run.checks).enumerate() {
+            let detail = match part {
+                Verdict::Failed(err) => format!("<br><span class=\"bad\">{}</span>", escape(err)),
+                _ => String::new(),
+            };
+            let _ = write!(
+                html,
+                "<tr><td>{}</td><td>{}{detail}</td><td>{}</td><td>{}</td></tr>",
+                index + 1,
+                escape(part.answer()),
+                part.time(),
+                match check {
+                    Check::Verified => "<span class=\"ok\">verified</span>",
+                    Check::Mismatch =>
+                        "<span class=\"bad\">does not match the recorded answer</span>",
+                    Check::Unverified => "<span class=\"muted\">no recorded answer</span>",
+                }
+            );
+        }
+        html.push_str("</table>");
+
+        if let Some(drawing) = drawing(day, &runner::default_input_path(day)) {
+            html.push_str("<h2>Input</h2>");
+            match drawing {
+                Ok(svg) => html.push_str(&svg),
+                Err(err) => {
+                    let _ = write!(html, "<p class=\"bad\">{}</p>", escape(&err));
+                }
+            }
+        }
+
+        html.push_str("<h2>Timing history</h2>");
+        html.push_str(&history(day));
+        page(&format!("Day {day}"), &html)
+    }
+}
+
+fn status(run: &Run) -> String {
+    let failed = run
+        .parts
+        .iter()
+        .any(|part| matches!(part, Verdict::Failed(_) | Verdict::Timeout(_)));
+    if failed {
+        "<span class=\"bad\">failed</span>"
+    } else if run.checks.contains(&Check::Mismatch) {
+        "<span class=\"bad\">mismatch</span>"
+    } else if run.checks.contains(&Check::Verified) {
+        "<span class=\"ok\">verified</span>"
+    } else {
+        "<span class=\"muted\">unverified</span>"
+    }
+    .to_string()
+}
+
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{}</title>\
+         <style>{STYLE}</style></head><body>{body}</body></html>",
+        escape(title)
+    )
+}
+
+fn reply(stream: &TcpStream, status: &str, kind: &str, body: &str) -> Result<(), String> {
+    write!(
+        &*stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {kind}; charset=utf-8\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn redirect(stream: &TcpStream, location: &str) -> Result<(), String> {
+    write!(
+        &*stream,
+        "HTTP/1.1 303 See Other\r\nLocation: {location}\r\n\
+         Content-Length: 0\r\nConnection: close\r\n\r\n"
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn not_found(stream: &TcpStream) -> Result<(), String> {
+    reply(stream, "404 Not Found", "text/plain", "not found")
+}
+
+/// The last recorded runs of each stage of `day`, most recent first.
+fn history(day: u8) -> String {
+    let records = match perf::load(&perf::history_path()) {
+        Ok(records) => records,
+        Err(_) => return "<p class=\"muted\">no benchmark recorded yet</p>".to_string(),
+    };
+    let records: Vec<&Record> = records.iter().filter(|r| r.day == day).collect();
+    if records.is_empty() {
+        return "<p class=\"muted\">no benchmark recorded yet</p>".to_string();
+    }
+    let mut html = String::from(
+        "<table><tr><th>revision</th><th>input</th><th>stage</th>\
+         <th>median</th><th>runs</th><th>machine</th></tr>",
+    );
+    for record in records.iter().rev().take(30) {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.2?}</td><td>{}</td><td>{}</td></tr>",
+            escape(&record.revision),
+            escape(&record.input),
+            escape(&record.stage),
+            record.median,
+            record.runs,
+            escape(&record.machine),
+        );
+    }
+    html.push_str("</table>");
+    html
+}
+
+/// Maps the bounding box of some points into the drawing, keeping their
+/// aspect ratio.
+struct Frame {
+    min: [f64; 2],
+    scale: f64,
+}
+
+impl Frame {
+    fn fit(points: impl Iterator<Item = [f64; 2]>) -> Self {
+        let mut min = [f64::MAX; 2];
+        let mut max = [f64::MIN; 2];
+        for point in points {
+            for axis in 0..2 {
+                min[axis] = min[axis].min(point[axis]);
+                max[axis] = max[axis].max(point[axis]);
+            }
+        }
+        let extent = (max[0] - min[0]).max(max[1] - min[1]).max(1.0);
+        Self {
+            min,
+            scale: (SIZE - 20.0) / extent,
+        }
+    }
+
+    fn map(&self, point: [f64; 2]) -> [f64; 2] {
+        [
+            10.0 + (point[0] - self.min[0]) * self.scale,
+            10.0 + (point[1] - self.min[1]) * self.scale,
+        ]
+    }
+}
+
+fn svg(content: &str) -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{SIZE}\" height=\"{SIZE}\" \
+         viewBox=\"0 0 {SIZE} {SIZE}\">{content}</svg>"
+    )
+}
+
+/// Parses an input of a day for drawing it.
+fn parse<I>(day: Day<I>, path: &Path) -> Result<I, String> {
+    let input = runner::read_input(path, day.trim)?;
+    runner::quietly(|| runner::catch(|| (day.parse)(input)))
+}
+
+/// A drawing of an input of the days that lend themselves to one, as an
+/// HTML fragment.
+pub(crate) fn drawing(day: u8, path: &Path) -> Option<Result<String, String>> {
+    match day {
+        8 => Some(parse(day08::day(), path).map(|input| circuits(&input))),
+        9 => Some(parse(day09::day(), path).map(|input| polygon(&input))),
+        12 => Some(parse(day12::day(), path).map(|input| region_areas(&input))),
+        _ => None,
+    }
+}
+
+/// The junction boxes seen from above, with the links of part 1 and the
+/// largest circuits they form in colour.
+fn circuits(input: &day08::Input) -> String {
+    let params = day08::Params::configured();
+    let mut candidates = day08::create_candidates(input);
+    let mut circuits = day08::create_initial_circuits(input);
+    let links: Vec<[day08::Loc; 2]> = (0..params.links.min(candidates.len()))
+        .map(|_| day08::create_link(&mut circuits, &mut candidates))
+        .collect();
+    circuits.sort_by_key(|circuit| std::cmp::Reverse(circuit.len()));
+    let colours: HashMap<day08::Loc, &str> = circuits
+        .iter()
+        .zip(PALETTE)
+        .flat_map(|(circuit, colour)| circuit.iter().map(move |&loc| (loc, colour)))
+        .collect();
+
+    let flat = |loc: &day08::Loc| [loc[0] as f64, loc[1] as f64];
+    let frame = Frame::fit(input.iter().map(flat));
+    let mut content = String::new();
+    for [a, b] in &links {
+        let ([x1, y1], [x2, y2]) = (frame.map(flat(a)), frame.map(flat(b)));
+        let _ = write!(
+            content,
+            "<line x1=\"{x1:.1}\" y1=\"{y1:.1}\" x2=\"{x2:.1}\" y2=\"{y2:.1}\" stroke=\"#bbb\"/>"
+        );
+    }
+    for loc in input {
+        let [x, y] = frame.map(flat(loc));
+        let colour = colours.get(loc).copied().unwrap_or("#999");
+        let _ = write!(
+            content,
+            "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"2.5\" fill=\"{colour}\"/>"
+        );
+    }
+    format!(
+        "<p>{} links seen along the Z axis; the {} largest circuits are coloured.</p>{}",
+        links.len(),
+        PALETTE.len().min(circuits.len()),
+        svg(&content)
+    )
+}
+
+/// The loop of red tiles.
+fn polygon(input: &day09::Input) -> String {
+    let flat = |loc: &day09::Loc| [loc[0] as f64, loc[1] as f64];
+    let frame = Frame::fit(input.iter().map(flat));
+    let points: Vec<String> = input
+        .iter()
+        .map(|loc| {
+            let [x, y] = frame.map(flat(loc));
+            format!("{x:.1},{y:.1}")
+        })
+        .collect();
+    let mut content = format!(
+        "<polygon points=\"{}\" fill=\"#2ca02c\" fill-opacity=\".25\" stroke=\"#2ca02c\"/>",
+        points.join(" ")
+    );
+    for point in &points {
+        let (x, y) = point.split_once(',').unwrap();
+        let _ = write!(
+            content,
+            "<circle cx=\"{x}\" cy=\"{y}\" r=\"2\" fill=\"#d62728\"/>"
+        );
+    }
+    format!("<p>{} red tiles.</p>{}", input.len(), svg(&content))
+}
+
+/// The shapes of the presents, and the area each region needs against the
+/// area it has: the regions below the diagonal pass the check of part 1.
+fn region_areas(input: &day12::Input) -> String {
+    let mut shapes = String::new();
+    for (index, brick) in input.bricks.iter().enumerate() {
+        let cells: String = brick
+            .iter()
+            .map(|[i, j]| {
+                format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"9\" height=\"9\" fill=\"{}\"/>",
+                    j * 10,
+                    i * 10,
+                    PALETTE[index % PALETTE.len()]
+                )
+            })
+            .collect();
+        let _ = write!(
+            shapes,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"50\" height=\"50\" \
+             style=\"margin-right:.5em\">{cells}</svg>"
+        );
+    }
+
+    let areas: Vec<[f64; 2]> = input
+        .regions
+        .iter()
+        .map(|region| {
+            let needed: usize = region
+                .requirements
+                .iter()
+                .zip(&input.bricks)
+                .map(|(count, brick)| count * brick.len())
+                .sum();
+            [(region.size[0] * region.size[1]) as f64, needed as f64]
+        })
+        .collect();
+    let largest = areas
+        .iter()
+        .flatten()
+        .fold(1.0, |largest: f64, &area| largest.max(area));
+    let frame = Frame::fit([[0.0, 0.0], [largest, largest]].into_iter());
+    let [x0, y0] = frame.map([0.0, largest]);
+    let [x1, y1] = frame.map([largest, 0.0]);
+    let mut content = format!(
+        "<line x1=\"{x0:.1}\" y1=\"{y0:.1}\" x2=\"{x1:.1}\" y2=\"{y1:.1}\" stroke=\"#bbb\"/>"
+    );
+    for &[available, needed] in &areas {
+        let [x, y] = frame.map([available, largest - needed]);
+        let colour = if needed <= available {
+            PALETTE[2]
+        } else {
+            PALETTE[0]
+        };
+        let _ = write!(
+            content,
+            "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"2.5\" fill=\"{colour}\"/>"
+        );
+    }
+    format!(
+        "<p>The shapes of the presents:</p><p>{shapes}</p>\
+         <p>Each region by its area (across) and the area of its presents (up); \
+         the {} in green have room for them.</p>{}",
+        areas.iter().filter(|[a, n]| n <= a).count(),
+        svg(&content)
+    )
+}
@@ -0,0 +1,1130 @@
+//! Day 1: Secret Entrance.
+//!
+//! ```
+//! use aoc_2025::day01::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+//! assert_eq!(solve_part1(&input), 3);
+//! assert_eq!(solve_part2(&input), 6);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    config::{self, Config},
+    input::Trim,
+    numeric::checked,
+    parse::{Parser, lines, map, number, or, pair, parse, tag, take_while1, terminated},
+    repl::{self, Command},
+    report,
+    runner::{self, Day},
+    shrink::LINES,
+    stream::{Streaming, stream},
+};
+use std::{collections::HashMap, ops::Range, str::FromStr};
+
+pub type Number = u64;
+/// A number on the dial.
+pub type Position = u64;
+/// A count of clicks, which the longest rotations on the smallest dials
+/// add up past a `u64`.
+pub type Clicks = u128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub distance: Number,
+}
+
+/// The geometry of the dial: how many numbers it has and where it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub size: Position,
+    pub start: Position,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: 100,
+            start: 50,
+        }
+    }
+}
+
+impl Params {
+    /// The parameters of the `day01` table of the configuration, falling
+    /// back to the puzzle's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the puzzle cannot take them, which [`config::install`]
+    /// rules out.
+    pub fn configured() -> Self {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The parameters of the `day01` table of `config`, or why the dial
+    /// cannot have them.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let default = Self::default();
+        let params = Self {
+            size: config.try_param(1, "size")?.unwrap_or(default.size),
+            start: config.try_param(1, "start")?.unwrap_or(default.start),
+        };
+        if params.size == 0 {
+            Err("day01.size must be at least 1".to_string())
+        } else if params.start >= params.size {
+            Err(format!(
+                "day01.start must be below the {} numbers of the dial",
+                params.size
+            ))
+        } else {
+            Ok(params)
+        }
+    }
+}
+
+/// The safe's dial, numbered from 0 to `size - 1`. The puzzle's has 100
+/// numbers and starts at 50.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    position: Position,
+    size: Position,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dial {
+    pub fn new() -> Self {
+        Self::with(&Params::default())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the dial has no numbers or does not start on one of them.
+    ///
+    /// ```
+    /// use aoc_2025::day01::{Dial, Direction, Params, Rotation};
+    ///
+    /// let left = Rotation { direction: Direction::Left, distance: 7 };
+    /// let mut single = Dial::with(&Params { size: 1, start: 0 });
+    /// assert_eq!(single.turn(&left), 7);
+    ///
+    /// let mut huge = Dial::with(&Params { size: u64::MAX, start: u64::MAX - 3 });
+    /// let right = Rotation { direction: Direction::Right, distance: 5 };
+    /// assert_eq!(huge.turn(&right), 1);
+    /// assert_eq!(huge.position(), 2);
+    /// assert_eq!(huge.turn(&left), 1);
+    /// assert_eq!(huge.position(), u64::MAX - 5);
+    /// ```
+    pub fn with(params: &Params) -> Self {
+        assert!(params.size > 0, "the dial needs at least one number");
+        assert!(
+            params.start < params.size,
+            "the dial cannot start at {} with {} numbers",
+            params.start,
+            params.size
+        );
+        Self {
+            position: params.start,
+            size: params.size,
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Applies a rotation and returns how many clicks pointed the dial at 0,
+    /// including the one it ends on.
+    ///
+    /// ```
+    /// use aoc_2025::day01::{Dial, Direction, Rotation};
+    ///
+    /// let mut dial = Dial::new();
+    /// let rotation = Rotation { direction: Direction::Right, distance: 1000 };
+    /// assert_eq!(dial.turn(&rotation), 10);
+    /// assert_eq!(dial.position(), 50);
+    ///
+    /// let rotation = Rotation { direction: Direction::Left, distance: u64::MAX };
+    /// assert_eq!(dial.turn(&rotation), 184467440737095516);
+    /// assert_eq!(dial.position(), 35);
+    /// ```
+    pub fn turn(&mut self, rotation: &Rotation) -> Clicks {
+        if rotation.direction == Direction::Left {
+            self.position = (self.size - self.position) % self.size;
+        }
+        // The sum overflows the positions and distances of the largest ones.
+        let sum = self.position as u128 + rotation.distance as u128;
+        let times_at_zero = sum / self.size as u128;
+        self.position = (sum % self.size as u128) as Position;
+        if rotation.direction == Direction::Left {
+            self.position = (self.size - self.position) % self.size;
+        }
+        times_at_zero
+    }
+
+    /// How many clicks of a rotation would point the dial at `target`,
+    /// including the one it would end on. The count only depends on how far
+    /// the rotation must go to first reach the target.
+    ///
+    /// ```
+    /// use aoc_2025::day01::{Dial, Direction, Params, Rotation};
+    ///
+    /// let dial = Dial::new();
+    /// let rotation = Rotation { direction: Direction::Left, distance: 160 };
+    /// assert_eq!(dial.clicks_at(&rotation, 0), 2);
+    /// assert_eq!(dial.clicks_at(&rotation, 90), 2);
+    /// assert_eq!(dial.clicks_at(&rotation, 50), 1);
+    ///
+    /// let huge = Dial::with(&Params { size: u64::MAX, start: u64::MAX - 3 });
+    /// let right = Rotation { direction: Direction::Right, distance: 5 };
+    /// assert_eq!(huge.clicks_at(&right, 0), 1);
+    /// assert_eq!(huge.clicks_at(&right, 2), 1);
+    /// assert_eq!(huge.clicks_at(&right, 3), 0);
+    /// let left = Rotation { direction: Direction::Left, distance: 5 };
+    /// assert_eq!(huge.clicks_at(&left, u64::MAX - 5), 1);
+    /// assert_eq!(huge.clicks_at(&left, 0), 0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the target is not on the dial.
+    pub fn clicks_at(&self, rotation: &Rotation, target: Position) -> Clicks {
+        assert!(
+            target < self.size,
+            "{target} is not on a dial of {} numbers",
+            self.size
+        );
+        let behind = match rotation.direction {
+            Direction::Right => self.position as u128 + (self.size - target) as u128,
+            Direction::Left => target as u128 + (self.size - self.position) as u128,
+        } % self.size as u128;
+        (behind + rotation.distance as u128) / self.size as u128
+    }
+}
+
+/// Parses a rotation such as `L68`.
+///
+/// # Panics
+///
+/// Panics if the line is not a direction followed by a distance, or if the
+/// distance does not fit in a [`Number`].
+///
+/// ```should_panic
+/// aoc_2025::day01::parse_rotation("R18446744073709551616");
+/// ```
+pub fn parse_rotation(line: &str) -> Rotation {
+    parse(line, &rotation())
+}
+
+fn rotation<'a>() -> impl Parser<'a, Rotation> {
+    let direction = or(
+        map(tag("L"), |_| Direction::Left),
+        map(tag("R"), |_| Direction::Right),
+    );
+    map(pair(direction, number()), |(direction, distance)| {
+        Rotation {
+            direction,
+            distance,
+        }
+    })
+}
+
+pub type Input = Vec<Rotation>;
+
+pub fn parse_input(input: &str) -> Input {
+    parse(input, &lines(rotation()))
+}
+
+/// Counts the rotations that leave the dial at 0.
+pub fn solve_part1(input: &Input) -> usize {
+    solve_part1_with(input, &Params::default())
+}
+
+pub fn solve_part1_with(input: &Input, params: &Params) -> usize {
+    input
+        .iter()
+        .fold((Dial::with(params), 0), |acc, x| {
+            let mut dial = acc.0;
+            dial.turn(x);
+            (dial, acc.1 + if dial.position == 0 { 1 } else { 0 })
+        })
+        .1
+}
+
+/// Counts the clicks that point the dial at 0.
+pub fn solve_part2(input: &Input) -> Clicks {
+    solve_part2_with(input, &Params::default())
+}
+
+pub fn solve_part2_with(input: &Input, params: &Params) -> Clicks {
+    input
+        .iter()
+        .fold((Dial::with(params), 0), |acc, x| {
+            let mut dial = acc.0;
+            let count = dial.turn(x);
+            (dial, acc.1 + count)
+        })
+        .1
+}
+
+/// Counts the rotations that leave the dial on any of the targets, and the
+/// clicks that point it at any of them, in a constant time per rotation and
+/// target.
+///
+/// ```
+/// use aoc_2025::day01::{Params, count_at, parse_input};
+///
+/// let input = parse_input("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+/// assert_eq!(count_at(&input, &Params::default(), &[0]), (3, 6));
+/// assert_eq!(count_at(&input, &Params::default(), &[32, 0, 32]), (4, 11));
+/// ```
+///
+/// # Panics
+///
+/// Panics if a target is not on the dial.
+pub fn count_at(input: &Input, params: &Params, targets: &[Position]) -> (usize, Clicks) {
+    let mut targets = targets.to_vec();
+    targets.sort_unstable();
+    targets.dedup();
+    let mut dial = Dial::with(params);
+    let (mut landings, mut clicks) = (0, 0);
+    for rotation in input {
+        clicks += targets
+            .iter()
+            .map(|&target| dial.clicks_at(rotation, target))
+            .sum::<Clicks>();
+        dial.turn(rotation);
+        landings += usize::from(targets.binary_search(&dial.position).is_ok());
+    }
+    (landings, clicks)
+}
+
+/// For each position of the dial, how many rotations left it there and how
+/// many clicks pointed it there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub landings: Vec<usize>,
+    pub clicks: Vec<Clicks>,
+}
+
+/// A zeroed count for each position of the dial.
+///
+/// # Panics
+///
+/// Panics if there is not enough memory for them.
+fn counters<T: Clone + Default>(size: Position) -> Vec<T> {
+    let mut counters = Vec::new();
+    usize::try_from(size)
+        .ok()
+        .filter(|&size| counters.try_reserve_exact(size).is_ok())
+        .unwrap_or_else(|| panic!("cannot count the visits of {size} positions"));
+    counters.resize(size as usize, T::default());
+    counters
+}
+
+/// Builds the [`Histogram`] of the rotations in a constant time per
+/// rotation: each points the dial once at every position per full turn,
+/// and once more along the arc it covers after them, which a difference
+/// array marks by its ends.
+///
+/// ```
+/// use aoc_2025::day01::{Params, histogram, parse_input};
+///
+/// let input = parse_input("R7\nL2\n");
+/// let histogram = histogram(&input, &Params { size: 5, start: 0 });
+/// assert_eq!(histogram.landings, [1, 0, 1, 0, 0]);
+/// assert_eq!(histogram.clicks, [2, 3, 2, 1, 1]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the dial has too many positions to count the visits of each.
+pub fn histogram(input: &Input, params: &Params) -> Histogram {
+    let mut landings: Vec<usize> = counters(params.size);
+    let mut arcs: Vec<i128> = counters(params.size + 1);
+    let size = params.size as usize;
+    let mut laps: Clicks = 0;
+    let mut dial = Dial::with(params);
+    for rotation in input {
+        laps += (rotation.distance / params.size) as Clicks;
+        let arc = (rotation.distance % params.size) as usize;
+        let start = dial.position as usize;
+        let first = match rotation.direction {
+            Direction::Right => (start + 1) % size,
+            Direction::Left => (start + (size - arc)) % size,
+        };
+        arcs[first] += 1;
+        if first + arc <= size {
+            arcs[first + arc] -= 1;
+        } else {
+            arcs[0] += 1;
+            arcs[first + arc - size] -= 1;
+        }
+        dial.turn(rotation);
+        landings[dial.position as usize] += 1;
+    }
+    let mut covered = 0;
+    let clicks = arcs[..size]
+        .iter()
+        .map(|arc| {
+            covered += arc;
+            laps + covered as Clicks
+        })
+        .collect();
+    Histogram { landings, clicks }
+}
+
+/// Both answers for every number the dial could start at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Starts {
+    pub part1: Vec<usize>,
+    pub part2: Vec<Clicks>,
+}
+
+/// Solves both parts for every start at once. Starting `s` numbers further
+/// turns the dial through the same numbers `s` further, so it lands on and
+/// points at 0 as often as the dial started at 0 does at `-s`, which the
+/// [`histogram`] of that dial counts.
+///
+/// ```
+/// use aoc_2025::day01::{Params, by_start, parse_input, solve_part1_with, solve_part2_with};
+///
+/// let input = parse_input("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+/// let starts = by_start(&input, 100);
+/// for start in [0, 7, 50, 99] {
+///     let params = Params { size: 100, start };
+///     assert_eq!(starts.part1[start as usize], solve_part1_with(&input, &params));
+///     assert_eq!(starts.part2[start as usize], solve_part2_with(&input, &params));
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the dial has too many numbers to count the answers of each.
+pub fn by_start(input: &Input, size: Position) -> Starts {
+    let histogram = histogram(input, &Params { size, start: 0 });
+    let size = size as usize;
+    let mirrored = |start: usize| (size - start) % size;
+    Starts {
+        part1: (0..size)
+            .map(|start| histogram.landings[mirrored(start)])
+            .collect(),
+        part2: (0..size)
+            .map(|start| histogram.clicks[mirrored(start)])
+            .collect(),
+    }
+}
+
+/// What consecutive rotations do to a dial, whatever number it starts at:
+/// how far they turn it, and how many clicks point it at 0 from each start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Effect {
+    shift: Position,
+    clicks: Vec<Clicks>,
+}
+
+impl Effect {
+    /// The effect of no rotation at all.
+    fn identity(size: Position) -> Self {
+        Self {
+            shift: 0,
+            clicks: counters(size),
+        }
+    }
+
+    fn of(rotation: &Rotation, size: Position) -> Self {
+        let mut clicks = counters(size);
+        let mut shift = 0;
+        for (start, clicks) in clicks.iter_mut().enumerate() {
+            let mut dial = Dial::with(&Params {
+                size,
+                start: start as Position,
+            });
+            *clicks = dial.turn(rotation);
+            shift = (dial.position + (size - start as Position)) % size;
+        }
+        Self { shift, clicks }
+    }
+
+    /// The effect of these rotations followed by the `next` ones.
+    fn then(&self, next: &Effect) -> Self {
+        let size = self.clicks.len() as Position;
+        let clicks = self
+            .clicks
+            .iter()
+            .enumerate()
+            .map(|(start, clicks)| {
+                let middle = (start as Position + self.shift) % size;
+                clicks + next.clicks[middle as usize]
+            })
+            .collect();
+        Self {
+            shift: (self.shift + next.shift) % size,
+            clicks,
+        }
+    }
+
+    /// Where the dial ends from `start`, and how many clicks point it at 0
+    /// on the way.
+    pub fn apply(&self, start: Position) -> (Position, Clicks) {
+        let size = self.clicks.len() as Position;
+        ((start + self.shift) % size, self.clicks[start as usize])
+    }
+}
+
+/// A segment tree over the rotations, each node holding the [`Effect`] of
+/// the rotations below it, so that the clicks at 0 of any run of rotations
+/// from any start take a logarithmic time, as do changes to the rotations.
+/// Each node tabulates every start, so the dial must be small enough for as
+/// many tables as there are rotations.
+///
+/// ```
+/// use aoc_2025::day01::{Direction, Rotation, RotationTree, parse_input};
+///
+/// let input = parse_input("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+/// let mut tree = RotationTree::new(&input, 100);
+/// assert_eq!(tree.query(0..10, 50), (32, 6));
+/// assert_eq!(tree.query(2..4, 0), (43, 0));
+///
+/// tree.update(0, Rotation { direction: Direction::Right, distance: 50 });
+/// assert_eq!(tree.query(0..1, 50), (0, 1));
+/// assert_eq!(tree.query(0..3, 50), (18, 2));
+/// ```
+pub struct RotationTree {
+    rotations: usize,
+    leaves: usize,
+    nodes: Vec<Effect>,
+}
+
+impl RotationTree {
+    /// # Panics
+    ///
+    /// Panics if the dial has too many numbers to tabulate the effects on
+    /// each.
+    pub fn new(input: &Input, size: Position) -> Self {
+        assert!(size > 0, "the dial needs at least one number");
+        let leaves = input.len().next_power_of_two();
+        let mut nodes = vec![Effect::identity(size); 2 * leaves];
+        for (i, rotation) in input.iter().enumerate() {
+            nodes[leaves + i] = Effect::of(rotation, size);
+        }
+        for i in (1..leaves).rev() {
+            nodes[i] = nodes[2 * i].then(&nodes[2 * i + 1]);
+        }
+        Self {
+            rotations: input.len(),
+            leaves,
+            nodes,
+        }
+    }
+
+    /// Replaces the rotation at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no rotation at `index`.
+    pub fn update(&mut self, index: usize, rotation: Rotation) {
+        assert!(index < self.rotations, "there is no rotation {index}");
+        let size = self.nodes[1].clicks.len() as Position;
+        let mut i = self.leaves + index;
+        self.nodes[i] = Effect::of(&rotation, size);
+        while i > 1 {
+            i /= 2;
+            self.nodes[i] = self.nodes[2 * i].then(&self.nodes[2 * i + 1]);
+        }
+    }
+
+    /// Where the rotations of `range` leave the dial from `start`, and how
+    /// many of their clicks point it at 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range goes past the rotations or the start is not on
+    /// the dial.
+    pub fn query(&self, range: Range<usize>, start: Position) -> (Position, Clicks) {
+        assert!(
+            range.end <= self.rotations,
+            "there are only {} rotations",
+            self.rotations
+        );
+        let size = self.nodes[1].clicks.len() as Position;
+        assert!(start < size, "{start} is not on a dial of {size} numbers");
+        // The nodes covering the range, gathered from both ends inwards.
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        let (mut low, mut high) = (range.start + self.leaves, range.end + self.leaves);
+        while low < high {
+            if low % 2 == 1 {
+                left.push(low);
+                low += 1;
+            }
+            if high % 2 == 1 {
+                high -= 1;
+                right.push(high);
+            }
+            low /= 2;
+            high /= 2;
+        }
+        left.into_iter().chain(right.into_iter().rev()).fold(
+            (start, 0),
+            |(position, clicks), node| {
+                let (end, more) = self.nodes[node].apply(position);
+                (end, clicks + more)
+            },
+        )
+    }
+}
+
+/// The name of a dial of a lock, as in `A:L30`. The puzzle's dial has an
+/// empty name.
+pub type Name = String;
+
+/// A rotation of one of the dials of a lock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub dial: Name,
+    pub rotation: Rotation,
+}
+
+/// The rotations of the dials of a lock, in order.
+pub type Moves = Vec<Move>;
+
+/// Parses a rotation of a named dial such as `A:L30`, or of the puzzle's
+/// dial such as `L30`.
+///
+/// # Panics
+///
+/// Panics if the line is neither.
+pub fn parse_move(line: &str) -> Move {
+    parse(line, &a_move())
+}
+
+fn a_move<'a>() -> impl Parser<'a, Move> {
+    let name = take_while1("a dial name", |c| c.is_ascii_alphanumeric());
+    or(
+        map(
+            pair(terminated(name, tag(":")), rotation()),
+            |(dial, rotation)| Move {
+                dial: dial.to_string(),
+                rotation,
+            },
+        ),
+        map(rotation(), |rotation| Move {
+            dial: Name::new(),
+            rotation,
+        }),
+    )
+}
+
+pub fn parse_moves(input: &str) -> Moves {
+    parse(input, &lines(a_move()))
+}
+
+/// Two dials geared together, as in `A->B=2`: each click of the driver
+/// turns the driven dial by `ratio` clicks, the other way when it is
+/// negative.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gear {
+    pub driver: Name,
+    pub driven: Name,
+    pub ratio: i64,
+}
+
+impl FromStr for Gear {
+    type Err = String;
+
+    fn from_str(gear: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid gear {gear}, expected one such as A->B=2");
+        let (driver, rest) = gear.split_once("->").ok_or_else(invalid)?;
+        let (driven, ratio) = rest.split_once('=').ok_or_else(invalid)?;
+        let ratio = ratio.trim().parse().map_err(|_| invalid())?;
+        if ratio == 0 {
+            return Err(format!("the gear {gear} does not turn {driven}"));
+        }
+        Ok(Self {
+            driver: driver.trim().to_string(),
+            driven: driven.trim().to_string(),
+            ratio,
+        })
+    }
+}
+
+impl Gear {
+    /// The gears of the `day01` table of the configuration, none by
+    /// default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if they do not parse, which [`config::install`] rules out.
+    pub fn configured() -> Vec<Self> {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The gears of the `day01` table of `config`, or why they do not parse.
+    pub fn from_config(config: &Config) -> Result<Vec<Self>, String> {
+        Ok(config.try_list(1, "gears")?.unwrap_or_default())
+    }
+}
+
+/// A dial of a lock, and how often it landed on and pointed at 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialTally {
+    pub name: Name,
+    pub dial: Dial,
+    pub landings: usize,
+    pub clicks: Clicks,
+}
+
+/// The dials a move of `driver` turns, the driver first, with how many clicks
+/// each turns for each click of the driver.
+///
+/// Gear trains may branch and join again, as long as every way from the
+/// driver to a dial gives it the same ratio, but may not loop back to a dial
+/// they already turned: a dial cannot turn two ways at once.
+fn train(gears: &[Gear], driver: &str) -> Result<Vec<(Name, i64)>, String> {
+    let mut train: Vec<(Name, i64)> = vec![(driver.to_string(), 1)];
+    let mut i = 0;
+    while let Some((name, ratio)) = train.get(i).cloned() {
+        for gear in gears.iter().filter(|gear| gear.driver == name) {
+            let geared = ratio
+                .checked_mul(gear.ratio)
+                .ok_or_else(|| format!("the gears turn {} too fast", gear.driven))?;
+            match train.iter().find(|(turned, _)| *turned == gear.driven) {
+                _ if gear.driven == driver => {
+                    return Err(format!("the gears turn {driver} again when it turns"));
+                }
+                Some(&(_, ratio)) if ratio == geared => {}
+                Some(&(_, ratio)) => {
+                    return Err(format!(
+                        "the gears turn {} both {ratio} and {geared} clicks for each click of {driver}",
+                        gear.driven
+                    ));
+                }
+                None => train.push((gear.driven.clone(), geared)),
+            }
+        }
+        i += 1;
+    }
+    Ok(train)
+}
+
+/// A lock of dials sharing the same geometry, some of them geared to
+/// others. Its dials appear as they first turn.
+///
+/// ```
+/// use aoc_2025::day01::{Lock, Params, parse_moves};
+///
+/// let moves = parse_moves("L50\nA:R50\nB:L25\n");
+/// let mut lock = Lock::new(Params::default(), vec!["A->B=-2".parse().unwrap()]).unwrap();
+/// moves.iter().for_each(|m| lock.turn(m));
+/// let counts: Vec<_> = lock
+///     .dials()
+///     .iter()
+///     .map(|d| (d.name.as_str(), d.dial.position(), d.landings, d.clicks))
+///     .collect();
+/// assert_eq!(counts, [("", 0, 1, 1), ("A", 0, 1, 1), ("B", 25, 0, 1)]);
+/// assert_eq!(lock.totals(), (2, 3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    params: Params,
+    /// The dials each driver turns, as [`train`] finds them.
+    trains: HashMap<Name, Vec<(Name, i64)>>,
+    dials: Vec<DialTally>,
+}
+
+impl Lock {
+    /// A lock whose gears may branch and join again, as long as every way
+    /// from a dial to another gives it the same ratio, but never loop.
+    ///
+    /// ```
+    /// use aoc_2025::day01::{Gear, Lock, Params};
+    ///
+    /// let gears = |gears: &[&str]| -> Vec<Gear> { gears.iter().map(|g| g.parse().unwrap()).collect() };
+    /// let diamond = gears(&["A->B=2", "A->C=-1", "B->D=-1", "C->D=2"]);
+    /// assert!(Lock::new(Params::default(), diamond).is_ok());
+    /// let jammed = gears(&["A->B=2", "A->C=1", "B->D=1", "C->D=1"]);
+    /// assert!(Lock::new(Params::default(), jammed).is_err());
+    /// let looped = gears(&["A->B=1", "B->A=1"]);
+    /// assert!(Lock::new(Params::default(), looped).is_err());
+    /// ```
+    pub fn new(params: Params, gears: Vec<Gear>) -> Result<Self, String> {
+        let trains = gears
+            .iter()
+            .map(|gear| Ok((gear.driver.clone(), train(&gears, &gear.driver)?)))
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            params,
+            trains,
+            dials: Vec::new(),
+        })
+    }
+
+    /// The lock of the configured dials and gears.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configured gears jam, which [`config::install`] rules
+    /// out.
+    pub fn configured() -> Self {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The lock of the dials and gears of the `day01` table of `config`, or
+    /// why there can be no such lock.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        Self::new(Params::from_config(config)?, Gear::from_config(config)?)
+            .map_err(|err| format!("day01.gears: {err}"))
+    }
+
+    pub fn dials(&self) -> &[DialTally] {
+        &self.dials
+    }
+
+    /// Turns a dial and every dial geared to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the gears turn a dial further than a [`Number`] of clicks.
+    pub fn turn(&mut self, m: &Move) {
+        let alone = [(m.dial.clone(), 1)];
+        let train = self.trains.get(&m.dial).map_or(&alone[..], Vec::as_slice);
+        for (name, ratio) in train {
+            let distance = checked(
+                m.rotation.distance.checked_mul(ratio.unsigned_abs()),
+                "geared rotation",
+            );
+            let direction = match (m.rotation.direction, *ratio < 0) {
+                (direction, false) => direction,
+                (Direction::Left, true) => Direction::Right,
+                (Direction::Right, true) => Direction::Left,
+            };
+            let index = match self.dials.iter().position(|dial| dial.name == *name) {
+                Some(index) => index,
+                None => {
+                    self.dials.push(DialTally {
+                        name: name.clone(),
+                        dial: Dial::with(&self.params),
+                        landings: 0,
+                        clicks: 0,
+                    });
+                    self.dials.len() - 1
+                }
+            };
+            let tally = &mut self.dials[index];
+            tally.clicks += tally.dial.turn(&Rotation {
+                direction,
+                distance,
+            });
+            tally.landings += usize::from(tally.dial.position == 0);
+        }
+    }
+
+    /// The landings on and clicks at 0 of all the dials.
+    pub fn totals(&self) -> (usize, Clicks) {
+        self.dials.iter().fold((0, 0), |(landings, clicks), dial| {
+            (landings + dial.landings, clicks + dial.clicks)
+        })
+    }
+}
+
+/// The rotations of the moves when they all turn the puzzle's dial alone.
+pub fn single(moves: &Moves, gears: &[Gear]) -> Option<Input> {
+    moves
+        .iter()
+        .map(|m| (m.dial.is_empty() && gears.is_empty()).then_some(m.rotation))
+        .collect()
+}
+
+/// The moves as the configured lock makes them.
+fn configured_lock(moves: &Moves) -> Lock {
+    let mut lock = Lock::configured();
+    moves.iter().for_each(|m| lock.turn(m));
+    lock
+}
+
+/// Both answers, accumulated one move at a time on the configured lock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tally {
+    pub lock: Lock,
+}
+
+impl Default for Tally {
+    fn default() -> Self {
+        Self {
+            lock: Lock::configured(),
+        }
+    }
+}
+
+impl Streaming for Tally {
+    type Line = Move;
+
+    fn parse_line(line: &str) -> Move {
+        parse_move(line)
+    }
+
+    fn push(&mut self, m: Move) {
+        self.lock.turn(&m);
+    }
+
+    fn answers(&self) -> [String; 2] {
+        let (landings, clicks) = self.lock.totals();
+        [landings.to_string(), clicks.to_string()]
+    }
+}
+
+/// The rotations of the puzzle's dial, for the commands that only know of
+/// it.
+fn rotations(moves: &Moves) -> Result<Input, String> {
+    single(moves, &Gear::configured())
+        .ok_or_else(|| "this only looks at a lock of a single dial without gears".to_string())
+}
+
+fn dial_after(moves: &Moves, args: &[&str]) -> Result<String, String> {
+    let count: usize = repl::arg(args[0], "move count")?;
+    let moves = moves
+        .get(..count)
+        .ok_or_else(|| format!("there are only {} moves", moves.len()))?;
+    let lock = runner::catch(|| configured_lock(&moves.to_vec()))?;
+    let lines: Vec<String> = lock
+        .dials()
+        .iter()
+        .map(|tally| {
+            let name = if tally.name.is_empty() {
+                String::new()
+            } else {
+                format!("{}: ", tally.name)
+            };
+            format!(
+                "{name}position {}, landed on zero {} time(s), pointed at zero {} time(s)",
+                tally.dial.position, tally.landings, tally.clicks
+            )
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn dials(moves: &Moves, _: &[&str]) -> Result<String, String> {
+    let lock = runner::catch(|| configured_lock(moves))?;
+    let mut lines: Vec<String> = lock
+        .dials()
+        .iter()
+        .map(|tally| {
+            let name = if tally.name.is_empty() {
+                "-"
+            } else {
+                &tally.name
+            };
+            format!(
+                "{name:>6}: landed on zero {}, pointed at zero {}",
+                tally.landings, tally.clicks
+            )
+        })
+        .collect();
+    let (landings, clicks) = lock.totals();
+    lines.push(format!(
+        " total: landed on zero {landings}, pointed at zero {clicks}"
+    ));
+    Ok(lines.join("\n"))
+}
+
+fn targets(moves: &Moves, args: &[&str]) -> Result<String, String> {
+    let input = &rotations(moves)?;
+    let params = Params::configured();
+    let targets = args
+        .iter()
+        .map(|&arg| {
+            repl::arg(arg, "position").and_then(|target: Position| {
+                if target < params.size {
+                    Ok(target)
+                } else {
+                    Err(format!(
+                        "{target} is not on a dial of {} numbers",
+                        params.size
+                    ))
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let (landings, clicks) = count_at(input, &params, &targets);
+    Ok(format!(
+        "landed on them {landings} time(s), pointed at them {clicks} time(s)"
+    ))
+}
+
+fn show_histogram(moves: &Moves, _: &[&str]) -> Result<String, String> {
+    let input = &rotations(moves)?;
+    let params = Params::configured();
+    let histogram = runner::catch(|| histogram(input, &params))?;
+    let lines: Vec<String> = histogram
+        .landings
+        .iter()
+        .zip(&histogram.clicks)
+        .enumerate()
+        .map(|(position, (landings, clicks))| {
+            format!("{position:>4}: landed {landings}, pointed at {clicks}")
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// The starts reaching the extreme counts, as `N at 1, 2`.
+fn extremes<T: Ord + Copy + std::fmt::Display>(counts: &[T]) -> [String; 2] {
+    let starts = |count: T| {
+        let starts: Vec<String> = (0..counts.len())
+            .filter(|&start| counts[start] == count)
+            .map(|start| start.to_string())
+            .collect();
+        let shown = starts.len().min(10);
+        let more = match starts.len() - shown {
+            0 => String::new(),
+            more => format!(" and {more} more"),
+        };
+        format!("{count} from {}{more}", starts[..shown].join(", "))
+    };
+    let (least, most) = (counts.iter().min(), counts.iter().max());
+    [most, least].map(|count| count.copied().map_or_else(String::new, starts))
+}
+
+fn starts(moves: &Moves, _: &[&str]) -> Result<String, String> {
+    let input = &rotations(moves)?;
+    let starts = runner::catch(|| by_start(input, Params::configured().size))?;
+    let [most1, least1] = extremes(&starts.part1);
+    let [most2, least2] = extremes(&starts.part2);
+    let mut lines = vec![
+        format!("part 1: most {most1}; fewest {least1}"),
+        format!("part 2: most {most2}; fewest {least2}"),
+    ];
+    lines.extend(
+        starts
+            .part1
+            .iter()
+            .zip(&starts.part2)
+            .enumerate()
+            .map(|(start, (part1, part2))| format!("{start:>4}: part 1 {part1}, part 2 {part2}")),
+    );
+    Ok(lines.join("\n"))
+}
+
+const COMMANDS: &[Command<Moves>] = &[
+    Command {
+        pattern: "dial after {}",
+        help: "dial states after the first N moves",
+        run: dial_after,
+    },
+    Command {
+        pattern: "dials",
+        help: "landings on and clicks at zero of each dial",
+        run: dials,
+    },
+    Command {
+        pattern: "targets {}...",
+        help: "landings on and clicks at any of the positions",
+        run: targets,
+    },
+    Command {
+        pattern: "histogram",
+        help: "landings on and clicks at every position",
+        run: show_histogram,
+    },
+    Command {
+        pattern: "starts",
+        help: "both answers from every start, and the extreme ones",
+        run: starts,
+    },
+];
+
+/// Mirrors the moves from some of the times every dial of the lock sits at a
+/// position the mirror leaves in place, 0 or the opposite number: the
+/// mirrored dials pass 0 whenever the real ones do, whichever gears turn
+/// them. The dials keep their names.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let mut lock = Lock::configured();
+    let Params { size, start } = lock.params;
+    let is_fixed = |position: Position| (position as u128 * 2).is_multiple_of(size as u128);
+    let mut mirrored = false;
+    let mut output = String::new();
+    for m in parse_moves(input) {
+        if is_fixed(start) && lock.dials.iter().all(|tally| is_fixed(tally.dial.position)) {
+            mirrored = rng.coin();
+        }
+        let left = (m.rotation.direction == Direction::Left) != mirrored;
+        if !m.dial.is_empty() {
+            output += &format!("{}:", m.dial);
+        }
+        output += &format!("{}{}\n", if left { 'L' } else { 'R' }, m.rotation.distance);
+        lock.turn(&m);
+    }
+    output
+}
+
+/// The figures of the report: the dials, and the rotations each way and
+/// their distances.
+fn stats(moves: &Moves) -> Vec<(&'static str, String)> {
+    let input: Input = moves.iter().map(|m| m.rotation).collect();
+    let mut dials: Vec<&str> = moves.iter().map(|m| m.dial.as_str()).collect();
+    dials.sort_unstable();
+    dials.dedup();
+    let left = input
+        .iter()
+        .filter(|rotation| rotation.direction == Direction::Left)
+        .count();
+    vec![
+        ("dials turned directly", dials.len().to_string()),
+        ("rotations", input.len().to_string()),
+        ("left rotations", left.to_string()),
+        ("right rotations", (input.len() - left).to_string()),
+        (
+            "distances",
+            report::span(input.iter().map(|rotation| rotation.distance)),
+        ),
+        (
+            "total distance",
+            input
+                .iter()
+                .map(|rotation| rotation.distance as u128)
+                .sum::<u128>()
+                .to_string(),
+        ),
+    ]
+}
+
+/// Solves a part on the puzzle's dial alone, or totals it over the dials of
+/// a lock.
+fn solve_moves(moves: &Moves, part: usize) -> String {
+    let params = Params::configured();
+    match (single(moves, &Gear::configured()), part) {
+        (Some(input), 1) => solve_part1_with(&input, &params).to_string(),
+        (Some(input), _) => solve_part2_with(&input, &params).to_string(),
+        (None, 1) => configured_lock(moves).totals().0.to_string(),
+        (None, _) => configured_lock(moves).totals().1.to_string(),
+    }
+}
+
+pub fn day() -> Day<Moves> {
+    Day {
+        number: 1,
+        trim: Trim::Both,
+        parse: parse_moves,
+        part1: |moves| solve_moves(moves, 1),
+        part2: Some(|moves| solve_moves(moves, 2)),
+        stream: Some(stream::<Tally>),
+        commands: COMMANDS,
+        shrink: &[LINES],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,300 @@
+//! Day 2: Gift Shop.
+//!
+//! ```
+//! use aoc_2025::day02::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("11-22,95-115,998-1012");
+//! assert_eq!(solve_part1(&input), 11 + 22 + 99 + 1010);
+//! assert_eq!(solve_part2(&input), 11 + 22 + 99 + 111 + 999 + 1010);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    input::Trim,
+    numeric::checked,
+    parse::{map, number, parse, separated, separated_pair, tag},
+    repl::{self, Command},
+    report,
+    runner::{Day, Strategy},
+    shrink::Structure,
+};
+use std::ops::RangeInclusive;
+
+pub type Number = u64;
+
+/// The inclusive ranges of IDs to check.
+pub type Input = Vec<RangeInclusive<Number>>;
+
+pub fn parse_input(input: &str) -> Input {
+    let range = map(
+        separated_pair(number(), tag("-"), number()),
+        |(start, end)| start..=end,
+    );
+    parse(input, &separated(range, tag(",")))
+}
+
+/// Whether the ID is made of some digits repeated twice.
+pub fn is_obviously_invalid(n: Number) -> bool {
+    let s = format!("{n}");
+    s.len() % 2 == 0 && s[0..s.len() / 2] == s[s.len() / 2..]
+}
+
+/// Sums the IDs of the ranges that [`is_obviously_invalid`], block length by
+/// block length rather than ID by ID.
+///
+/// ```
+/// use aoc_2025::day02::{solve_part1, solve_part2};
+///
+/// let input = vec![1..=999_999_999_999];
+/// assert_eq!(solve_part1(&input), 495_495_540_949_540_950);
+/// assert_eq!(solve_part2(&input), 500_397_481_094_131_395);
+/// ```
+pub fn solve_part1(input: &Input) -> Number {
+    let sum = input
+        .iter()
+        .flat_map(|range| (1..=MAX_DIGITS / 2).map(|block| sum_repeated(range, 2 * block, block)))
+        .sum::<u128>();
+    checked(u64::try_from(sum).ok(), "the sum of the invalid IDs")
+}
+
+/// Sums the IDs of the ranges that [`is_obviously_invalid`] by checking them
+/// one by one.
+pub fn scan_part1(input: &Input) -> Number {
+    input
+        .iter()
+        .flat_map(|range| range.clone().filter(|&n| is_obviously_invalid(n)))
+        .sum()
+}
+
+/// The most digits an ID can have.
+const MAX_DIGITS: u32 = Number::MAX.ilog10() + 1;
+
+/// The IDs of `range` that have `length` digits and are made of a block of
+/// `block` digits repeated: those are the blocks times the pattern
+/// `1…01…01`, so they are the pattern and a range of blocks, if any.
+fn repeated(range: &RangeInclusive<Number>, length: u32, block: u32) -> Option<(u128, u128, u128)> {
+    let pattern: u128 = (0..length / block).map(|i| 10u128.pow(i * block)).sum();
+    let low = u128::from(*range.start()).max(10u128.pow(length - 1));
+    let high = u128::from(*range.end()).min(10u128.pow(length) - 1);
+    let first = low.div_ceil(pattern).max(10u128.pow(block - 1));
+    let last = (high / pattern).min(10u128.pow(block) - 1);
+    (low <= high && first <= last).then_some((pattern, first, last))
+}
+
+/// Sums the IDs of `range` that have `length` digits and are made of a block
+/// of `block` digits repeated, which add up to a sum of consecutive blocks.
+fn sum_repeated(range: &RangeInclusive<Number>, length: u32, block: u32) -> u128 {
+    repeated(range, length, block).map_or(0, |(pattern, first, last)| {
+        (first + last) * (last - first + 1) / 2 * pattern
+    })
+}
+
+/// The least and the greatest IDs of `range` that [`is_invalid`], found
+/// among the repeated blocks of each length rather than ID by ID.
+fn invalid_bounds(range: &RangeInclusive<Number>) -> Option<(Number, Number)> {
+    (2..=MAX_DIGITS)
+        .flat_map(|length| {
+            (1..length)
+                .filter(move |block| length.is_multiple_of(*block))
+                .filter_map(move |block| repeated(range, length, block))
+        })
+        .map(|(pattern, first, last)| (first * pattern, last * pattern))
+        .reduce(|(low, high), (first, last)| (low.min(first), high.max(last)))
+        .map(|(low, high)| (low as Number, high as Number))
+}
+
+/// The prime factors of `n`, once each.
+fn prime_factors(n: u32) -> Vec<u32> {
+    (2..=n)
+        .filter(|&factor| n.is_multiple_of(factor))
+        .filter(|&factor| (2..factor).all(|d| !factor.is_multiple_of(d)))
+        .collect()
+}
+
+/// Sums the IDs of `range` that have `length` digits and are made of a
+/// repeated block of any length. An ID repeating a block repeats the blocks of
+/// `length / p` digits for some prime `p`, and one repeating blocks for two
+/// such lengths repeats the blocks of their gcd, so inclusion–exclusion over
+/// the sets of primes counts each ID once.
+fn sum_invalid(range: &RangeInclusive<Number>, length: u32) -> u128 {
+    let primes = prime_factors(length);
+    let (mut added, mut removed) = (0, 0);
+    for subset in 1..1u32 << primes.len() {
+        let chosen = primes
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| subset & 1 << i != 0);
+        let block = chosen.clone().fold(length, |block, (_, p)| block / p);
+        if chosen.count() % 2 == 1 {
+            added += sum_repeated(range, length, block);
+        } else {
+            removed += sum_repeated(range, length, block);
+        }
+    }
+    added - removed
+}
+
+/// Whether the ID is made of some digits repeated at least twice.
+///
+/// ```
+/// use aoc_2025::day02::is_invalid;
+///
+/// assert!(is_invalid(824824824));
+/// assert!(!is_invalid(824824825));
+/// ```
+pub fn is_invalid(n: Number) -> bool {
+    let s = format!("{n}");
+    (2..=s.len()).any(|i| {
+        s.len() % i == 0
+            && (1..i).all(|j| s[0..s.len() / i] == s[j * s.len() / i..(j + 1) * s.len() / i])
+    })
+}
+
+/// Sums the IDs of the ranges that [`is_invalid`], ID length by ID length
+/// rather than ID by ID.
+pub fn solve_part2(input: &Input) -> Number {
+    let sum = input
+        .iter()
+        .flat_map(|range| (2..=MAX_DIGITS).map(|length| sum_invalid(range, length)))
+        .sum::<u128>();
+    checked(u64::try_from(sum).ok(), "the sum of the invalid IDs")
+}
+
+/// Sums the IDs of the ranges that [`is_invalid`] by checking them one by one.
+pub fn scan_part2(input: &Input) -> Number {
+    input
+        .iter()
+        .flat_map(|range| range.clone().filter(|&n| is_invalid(n)))
+        .sum()
+}
+
+fn invalid(_: &Input, args: &[&str]) -> Result<String, String> {
+    let id = repl::arg(args[0], "ID")?;
+    Ok(format!(
+        "part 1: {}, part 2: {}",
+        if is_obviously_invalid(id) {
+            "invalid"
+        } else {
+            "valid"
+        },
+        if is_invalid(id) { "invalid" } else { "valid" }
+    ))
+}
+
+const COMMANDS: &[Command<Input>] = &[Command {
+    pattern: "invalid {}",
+    help: "whether an ID is invalid in each part",
+    run: invalid,
+}];
+
+const STRATEGIES: &[Strategy<Input>] = &[
+    Strategy {
+        name: "closed-form",
+        help: "sums the repeated blocks of each length arithmetically",
+        part1: Some(|input| solve_part1(input).to_string()),
+        part2: Some(|input| solve_part2(input).to_string()),
+    },
+    Strategy {
+        name: "scan",
+        help: "checks every ID of the ranges",
+        part1: Some(|input| scan_part1(input).to_string()),
+        part2: Some(|input| scan_part2(input).to_string()),
+    },
+];
+
+/// Shrinks the inputs range by range.
+const RANGES: Structure = Structure {
+    count: |input| input.trim().split(',').count(),
+    keep: |input, kept| {
+        let ranges: Vec<&str> = input.trim().split(',').collect();
+        let kept: Vec<&str> = kept.iter().map(|&i| ranges[i]).collect();
+        kept.join(",") + "\n"
+    },
+};
+
+/// Shuffles the ranges and moves their bounds inwards, short of the first
+/// and last IDs they hold that [`is_invalid`], so that they hold the same
+/// invalid IDs.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let mut ranges = parse_input(input);
+    rng.shuffle(&mut ranges);
+    let ranges: Vec<String> = ranges
+        .into_iter()
+        .map(|range| {
+            let (start, end) = (*range.start(), *range.end());
+            let (start, end) = match invalid_bounds(&range) {
+                Some((first, last)) => (rng.between(start, first), rng.between(last, end)),
+                _ if start > end => (start, end),
+                _ => {
+                    let start = rng.between(start, end);
+                    (start, rng.between(start, end))
+                }
+            };
+            format!("{start}-{end}")
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+/// The figures of the report: the ranges, the IDs they span and the digits
+/// of those IDs.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    let bounds = input
+        .iter()
+        .flat_map(|range| [*range.start(), *range.end()]);
+    let lengths = input.iter().map(|range| {
+        let (start, end) = (*range.start(), *range.end());
+        if start > end {
+            0
+        } else {
+            u128::from(end - start) + 1
+        }
+    });
+    vec![
+        ("ranges", input.len().to_string()),
+        ("IDs", report::span(bounds.clone())),
+        ("IDs checked", lengths.clone().sum::<u128>().to_string()),
+        ("range lengths", report::span(lengths)),
+        (
+            "digits",
+            report::span(bounds.map(|id| id.to_string().len())),
+        ),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 2,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: None,
+        commands: COMMANDS,
+        shrink: &[RANGES],
+        strategies: STRATEGIES,
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_bounds_match_a_scan() {
+        for start in (0..2_000).step_by(37) {
+            for end in (start..12_000).step_by(311) {
+                let range = start..=end;
+                let first = range.clone().find(|&n| is_invalid(n));
+                let last = range.clone().rev().find(|&n| is_invalid(n));
+                assert_eq!(invalid_bounds(&range), first.zip(last), "{range:?}");
+            }
+        }
+        assert_eq!(
+            invalid_bounds(&(0..=Number::MAX)),
+            Some((11, 18_446_744_071_844_674_407))
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Day 3: Lobby.
+//!
+//! ```
+//! use aoc_2025::day03::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("987654321111111\n811111111111119\n");
+//! assert_eq!(solve_part1(&input), 98 + 89);
+//! assert_eq!(solve_part2(&input), 987654321111 + 811111111119);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    config::{self, Config},
+    input::Trim,
+    parse::{Parser, lines, map, parse, take_while1},
+    repl::{self, Command},
+    report,
+    runner::Day,
+    shrink::LINES,
+    stream::{Streaming, stream},
+};
+
+pub type Int = u64;
+
+/// The banks of batteries, as lists of joltage digits.
+pub type Input = Vec<Vec<Int>>;
+
+pub fn parse_bank(line: &str) -> Vec<Int> {
+    parse(line, &bank())
+}
+
+fn bank<'a>() -> impl Parser<'a, Vec<Int>> {
+    map(
+        take_while1("a joltage digit", |c| c.is_ascii_digit()),
+        |digits| digits.bytes().map(|digit| (digit - b'0') as Int).collect(),
+    )
+}
+
+pub fn parse_input(input: &str) -> Input {
+    parse(input, &lines(bank()))
+}
+
+/// The largest number formed by `digits` batteries of the bank, keeping
+/// their order.
+///
+/// # Panics
+///
+/// Panics if the bank holds fewer than `digits` batteries.
+///
+/// ```
+/// use aoc_2025::day03::max_joltage;
+///
+/// assert_eq!(max_joltage(&[8, 1, 8, 1, 9, 1], 3), 891);
+/// ```
+pub fn max_joltage(bank: &[Int], digits: usize) -> Int {
+    batteries(bank, digits)
+        .iter()
+        .fold(0, |result, &i| result * 10 + bank[i])
+}
+
+/// The positions of the batteries forming [`max_joltage`].
+fn batteries(bank: &[Int], digits: usize) -> Vec<usize> {
+    assert!(
+        digits <= bank.len(),
+        "a bank holds {} batteries, fewer than the {digits} to turn on",
+        bank.len()
+    );
+    let mut positions = Vec::with_capacity(digits);
+    let mut offset = 0;
+    for d in 1..=digits {
+        let remainder = &bank[offset..];
+        let digit = remainder[..remainder.len() + d - digits]
+            .iter()
+            .max()
+            .unwrap();
+        let pos = remainder.iter().position(|x| x == digit).unwrap();
+        positions.push(offset + pos);
+        offset += pos + 1;
+    }
+    positions
+}
+
+/// The most batteries a joltage can use and still fit in an [`Int`].
+pub const MAX_DIGITS: usize = Int::MAX.ilog10() as usize;
+
+/// How many batteries each part turns on in every bank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub digits: [usize; 2],
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { digits: [2, 12] }
+    }
+}
+
+impl Params {
+    /// The parameters of the `day03` table of the configuration, falling
+    /// back to the puzzle's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the puzzle cannot take them, which [`config::install`]
+    /// rules out.
+    pub fn configured() -> Self {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The parameters of the `day03` table of `config`, or why the puzzle
+    /// cannot take them.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let digits = match config.try_list(3, "digits")? {
+            Some(digits) => digits.try_into().map_err(|digits: Vec<usize>| {
+                format!(
+                    "day03.digits must hold one count per part, not {}",
+                    digits.len()
+                )
+            })?,
+            None => Self::default().digits,
+        };
+        if let Some(count) = digits
+            .iter()
+            .find(|count| !(1..=MAX_DIGITS).contains(*count))
+        {
+            return Err(format!(
+                "day03.digits must be between 1 and {MAX_DIGITS}, the most digits a joltage fits in, not {count}"
+            ));
+        }
+        Ok(Self { digits })
+    }
+}
+
+pub fn solve_part1(input: &Input) -> Int {
+    solve_with(input, Params::default().digits[0])
+}
+
+pub fn solve_part2(input: &Input) -> Int {
+    solve_with(input, Params::default().digits[1])
+}
+
+/// Sums the largest joltages of the banks using `digits` batteries each.
+pub fn solve_with(input: &Input, digits: usize) -> Int {
+    input.iter().map(|bank| max_joltage(bank, digits)).sum()
+}
+
+fn joltage(input: &Input, args: &[&str]) -> Result<String, String> {
+    let index: usize = repl::arg(args[0], "bank")?;
+    let digits: usize = repl::arg(args[1], "digit count")?;
+    let bank = input
+        .get(index)
+        .ok_or_else(|| format!("there are only {} banks", input.len()))?;
+    if digits == 0 || digits > bank.len() {
+        return Err(format!("bank {index} holds {} batteries", bank.len()));
+    }
+    Ok(max_joltage(bank, digits).to_string())
+}
+
+const COMMANDS: &[Command<Input>] = &[Command {
+    pattern: "joltage {} {}",
+    help: "largest joltage of a bank using N batteries",
+    run: joltage,
+}];
+
+/// Both answers, accumulated one bank at a time with the configured digit
+/// counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tally {
+    pub params: Params,
+    pub part1: Int,
+    pub part2: Int,
+}
+
+impl Default for Tally {
+    fn default() -> Self {
+        Self {
+            params: Params::configured(),
+            part1: 0,
+            part2: 0,
+        }
+    }
+}
+
+impl Streaming for Tally {
+    type Line = Vec<Int>;
+
+    fn parse_line(line: &str) -> Vec<Int> {
+        parse_bank(line)
+    }
+
+    fn push(&mut self, bank: Vec<Int>) {
+        self.part1 += max_joltage(&bank, self.params.digits[0]);
+        self.part2 += max_joltage(&bank, self.params.digits[1]);
+    }
+
+    fn answers(&self) -> [String; 2] {
+        [self.part1.to_string(), self.part2.to_string()]
+    }
+}
+
+/// Shuffles the banks and lowers some of the batteries neither part turns
+/// on: no number through a lowered battery can beat one that avoids it.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let params = Params::configured();
+    let mut banks = parse_input(input);
+    rng.shuffle(&mut banks);
+    let mut output = String::new();
+    for bank in &mut banks {
+        let mut on = vec![false; bank.len()];
+        for digits in params.digits {
+            for i in batteries(bank, digits) {
+                on[i] = true;
+            }
+        }
+        for (joltage, on) in bank.iter_mut().zip(on) {
+            if !on && *joltage > 1 && rng.coin() {
+                *joltage = rng.between(1, *joltage);
+            }
+        }
+        output.extend(bank.iter().map(|joltage| joltage.to_string()));
+        output.push('\n');
+    }
+    output
+}
+
+/// The figures of the report: the banks and their batteries.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    vec![
+        ("banks", input.len().to_string()),
+        (
+            "batteries per bank",
+            report::span(input.iter().map(Vec::len)),
+        ),
+        ("joltages", report::span(input.iter().flatten().copied())),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 3,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_with(input, Params::configured().digits[0]).to_string(),
+        part2: Some(|input| solve_with(input, Params::configured().digits[1]).to_string()),
+        stream: Some(stream::<Tally>),
+        commands: COMMANDS,
+        shrink: &[LINES],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,225 @@
+//! Day 4: Printing Department.
+//!
+//! ```
+//! use aoc_2025::day04::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("@@@\n@@@\n@@@\n");
+//! assert_eq!(solve_part1(&input), 4);
+//! assert_eq!(solve_part2(&input), 9);
+//! ```
+
+use crate::{
+    anonymize::{self, Rng},
+    config::{self, Config},
+    input::Trim,
+    parse::{lines, parse, take_while1},
+    repl::{self, Command},
+    report,
+    runner::{Day, Strategy},
+    shrink::LINES,
+};
+use std::collections::HashSet;
+
+pub type Coord = i32;
+pub type Loc = [Coord; 2];
+
+/// The locations of the paper rolls, as row and column.
+pub type Input = HashSet<Loc>;
+
+pub fn parse_input(input: &str) -> Input {
+    let rows = lines(take_while1("`@` or `.`", |c| c == '@' || c == '.'));
+    parse(input, &rows)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.bytes().enumerate().filter_map(move |(j, tile)| {
+                if tile == b'@' {
+                    Some([i as Coord, j as Coord])
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
+static MOVES: [Loc; 8] = [
+    [-1, -1],
+    [-1, 0],
+    [-1, 1],
+    [0, -1],
+    [0, 1],
+    [1, -1],
+    [1, 0],
+    [1, 1],
+];
+
+fn add(a: Loc, b: Loc) -> Loc {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+/// How few of its neighbours must hold a roll for a roll to be accessible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub threshold: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { threshold: 4 }
+    }
+}
+
+impl Params {
+    /// The parameters of the `day04` table of the configuration, falling
+    /// back to the puzzle's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the puzzle cannot take them, which [`config::install`]
+    /// rules out.
+    pub fn configured() -> Self {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The parameters of the `day04` table of `config`, or why they do not
+    /// parse.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        Ok(Self {
+            threshold: config
+                .try_param(4, "threshold")?
+                .unwrap_or(Self::default().threshold),
+        })
+    }
+}
+
+/// Whether fewer than `threshold` of the eight neighbours of `loc` hold a
+/// roll.
+pub fn accessible(input: &Input, loc: Loc, threshold: usize) -> bool {
+    MOVES
+        .iter()
+        .filter(|&&m| input.contains(&add(m, loc)))
+        .count()
+        < threshold
+}
+
+/// Counts the rolls that are [`accessible`].
+pub fn solve_part1(input: &Input) -> usize {
+    solve_part1_with(input, &Params::default())
+}
+
+pub fn solve_part1_with(input: &Input, params: &Params) -> usize {
+    input
+        .iter()
+        .filter(|&&loc| accessible(input, loc, params.threshold))
+        .count()
+}
+
+/// Counts the rolls removed by taking accessible ones away until none is
+/// left.
+pub fn solve_part2(input: &Input) -> usize {
+    solve_part2_with(input, &Params::default())
+}
+
+pub fn solve_part2_with(input: &Input, params: &Params) -> usize {
+    remove_by_worklist(input, params)
+}
+
+/// Removes one accessible roll at a time, searching the whole grid for the
+/// next one.
+pub fn remove_by_search(input: &Input, params: &Params) -> usize {
+    let mut state = input.clone();
+    while let Some(&loc) = state
+        .iter()
+        .find(|&&loc| accessible(&state, loc, params.threshold))
+    {
+        state.remove(&loc);
+    }
+    input.len() - state.len()
+}
+
+/// Removes the accessible rolls from a worklist, onto which the neighbours
+/// of every removed roll go, since only they can have become accessible.
+/// Removing a roll never blocks another one, so the order does not change
+/// which rolls end up removed.
+pub fn remove_by_worklist(input: &Input, params: &Params) -> usize {
+    let mut state = input.clone();
+    let mut worklist: Vec<Loc> = input.iter().copied().collect();
+    while let Some(loc) = worklist.pop() {
+        if state.contains(&loc) && accessible(&state, loc, params.threshold) {
+            state.remove(&loc);
+            worklist.extend(
+                MOVES
+                    .iter()
+                    .map(|&m| add(m, loc))
+                    .filter(|neighbour| state.contains(neighbour)),
+            );
+        }
+    }
+    input.len() - state.len()
+}
+
+fn accessible_at(input: &Input, args: &[&str]) -> Result<String, String> {
+    let loc = [repl::arg(args[0], "row")?, repl::arg(args[1], "column")?];
+    Ok(if !input.contains(&loc) {
+        "no roll there".to_string()
+    } else if accessible(input, loc, Params::configured().threshold) {
+        "accessible".to_string()
+    } else {
+        "blocked".to_string()
+    })
+}
+
+const COMMANDS: &[Command<Input>] = &[Command {
+    pattern: "accessible {} {}",
+    help: "whether the roll at a row and column is accessible",
+    run: accessible_at,
+}];
+
+const STRATEGIES: &[Strategy<Input>] = &[
+    Strategy {
+        name: "worklist",
+        help: "revisits only the neighbours of the removed rolls",
+        part1: None,
+        part2: Some(|input| remove_by_worklist(input, &Params::configured()).to_string()),
+    },
+    Strategy {
+        name: "find-loop",
+        help: "searches the whole grid again after every removal",
+        part1: None,
+        part2: Some(|input| remove_by_search(input, &Params::configured()).to_string()),
+    },
+];
+
+/// Turns or flips the grid, which moves the rolls without changing their
+/// neighbours.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let rows: Vec<&str> = input.lines().collect();
+    let rows = anonymize::reorient(&rows, rng.coin(), rng.coin(), rng.coin());
+    rows.join("\n") + "\n"
+}
+
+/// The figures of the report: the rolls and the area they spread over.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    vec![
+        ("rolls", input.len().to_string()),
+        ("rows", report::span(input.iter().map(|loc| loc[0]))),
+        ("columns", report::span(input.iter().map(|loc| loc[1]))),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 4,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1_with(input, &Params::configured()).to_string(),
+        part2: Some(|input| solve_part2_with(input, &Params::configured()).to_string()),
+        stream: None,
+        commands: COMMANDS,
+        shrink: &[LINES],
+        strategies: STRATEGIES,
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,245 @@
+//! Day 5: Cafeteria.
+//!
+//! ```
+//! use aoc_2025::day05::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n");
+//! assert_eq!(solve_part1(&input), 3);
+//! assert_eq!(solve_part2(&input), 14);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    input::Trim,
+    parse::{lines, map, number, parse, separated_pair, tag},
+    repl::{self, Command},
+    report,
+    runner::{Day, Strategy},
+    shrink::SECTION_LINES,
+};
+use std::cmp::max;
+
+pub type Int = u64;
+
+/// An inclusive range of fresh ingredient IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    pub begin: Int,
+    pub end: Int,
+}
+
+impl Range {
+    pub fn contains(&self, i: Int) -> bool {
+        i >= self.begin && i <= self.end
+    }
+
+    pub fn size(&self) -> usize {
+        (self.end - self.begin) as usize + 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub ranges: Vec<Range>,
+    pub ids: Vec<Int>,
+}
+
+pub fn parse_input(input: &str) -> Input {
+    let range = map(
+        separated_pair(number(), tag("-"), number()),
+        |(begin, end)| Range { begin, end },
+    );
+    let (ranges, ids) = parse(
+        input,
+        &separated_pair(lines(range), tag("\n\n"), lines(number())),
+    );
+    Input { ranges, ids }
+}
+
+/// Counts the available IDs that fall in some fresh range.
+pub fn solve_part1(input: &Input) -> usize {
+    fresh_by_sweep(input)
+}
+
+/// Counts the fresh IDs by checking every ID against every range.
+pub fn fresh_by_scan(input: &Input) -> usize {
+    input
+        .ids
+        .iter()
+        .filter(|&&id| input.ranges.iter().any(|range| range.contains(id)))
+        .count()
+}
+
+/// Counts the fresh IDs by walking the sorted IDs along the [`sweep`]ed
+/// ranges.
+pub fn fresh_by_sweep(input: &Input) -> usize {
+    let mut ids = input.ids.clone();
+    ids.sort_unstable();
+    let merged = sweep(&input.ranges);
+    let mut ranges = merged.iter().peekable();
+    ids.into_iter()
+        .filter(|&id| {
+            while ranges.next_if(|range| range.end < id).is_some() {}
+            ranges.peek().is_some_and(|range| range.contains(id))
+        })
+        .count()
+}
+
+/// Sorts the ranges and merges the overlapping ones, so that the result
+/// covers the same IDs with disjoint ranges. Merging in place makes it
+/// quadratic in the worst case; [`sweep`] does the same in one pass.
+///
+/// ```
+/// use aoc_2025::day05::{Range, merge};
+///
+/// let ranges = [
+///     Range { begin: 10, end: 14 },
+///     Range { begin: 3, end: 5 },
+///     Range { begin: 12, end: 18 },
+/// ];
+/// assert_eq!(
+///     merge(&ranges),
+///     [Range { begin: 3, end: 5 }, Range { begin: 10, end: 18 }]
+/// );
+/// ```
+pub fn merge(ranges: &[Range]) -> Vec<Range> {
+    let mut ranges = ranges.to_vec();
+
+    ranges.sort_unstable_by_key(|range| range.begin);
+
+    let mut i = 0;
+    while i + 1 < ranges.len() {
+        if ranges[i + 1].begin <= ranges[i].end {
+            ranges[i].end = max(ranges[i].end, ranges[i + 1].end);
+            ranges.remove(i + 1);
+        } else {
+            i += 1;
+        }
+    }
+
+    ranges
+}
+
+/// Merges the overlapping ranges as [`merge`] does, extending the last
+/// merged range or starting a new one for each range in order.
+pub fn sweep(ranges: &[Range]) -> Vec<Range> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort_unstable_by_key(|range| range.begin);
+
+    let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.begin <= last.end => last.end = max(last.end, range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Counts the IDs that fall in some fresh range.
+pub fn solve_part2(input: &Input) -> usize {
+    sweep(&input.ranges).iter().map(|range| range.size()).sum()
+}
+
+fn contains(input: &Input, args: &[&str]) -> Result<String, String> {
+    let id = repl::arg(args[0], "ID")?;
+    let ranges: Vec<String> = input
+        .ranges
+        .iter()
+        .filter(|range| range.contains(id))
+        .map(|range| format!("{}-{}", range.begin, range.end))
+        .collect();
+    Ok(if ranges.is_empty() {
+        "spoiled".to_string()
+    } else {
+        format!("fresh, in {}", ranges.join(" "))
+    })
+}
+
+const COMMANDS: &[Command<Input>] = &[Command {
+    pattern: "contains {}",
+    help: "the ranges holding an ingredient ID",
+    run: contains,
+}];
+
+const STRATEGIES: &[Strategy<Input>] = &[
+    Strategy {
+        name: "sweep",
+        help: "merges the sorted ranges in one pass and walks the sorted IDs",
+        part1: Some(|input| fresh_by_sweep(input).to_string()),
+        part2: Some(|input| solve_part2(input).to_string()),
+    },
+    Strategy {
+        name: "merge",
+        help: "merges the ranges in place and checks each ID against all of them",
+        part1: Some(|input| fresh_by_scan(input).to_string()),
+        part2: Some(|input| {
+            merge(&input.ranges)
+                .iter()
+                .map(|range| range.size())
+                .sum::<usize>()
+                .to_string()
+        }),
+    },
+];
+
+/// Shifts every ID by the same amount and shuffles the ranges and the IDs,
+/// none of which changes what is fresh nor how many IDs the ranges hold.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let Input {
+        mut ranges,
+        mut ids,
+    } = parse_input(input);
+    let numbers = ranges.iter().flat_map(|range| [range.begin, range.end]);
+    let numbers = numbers.chain(ids.iter().copied());
+    let (low, high) = numbers.fold((Int::MAX, 0), |(low, high), n| (low.min(n), high.max(n)));
+    let (low, high) = (low.min(high), high);
+    let to = rng.between(low / 2, low.saturating_mul(2).min(Int::MAX - (high - low)));
+    let shift = |n: Int| n - low + to;
+    rng.shuffle(&mut ranges);
+    rng.shuffle(&mut ids);
+    let ranges: Vec<String> = ranges
+        .iter()
+        .map(|range| format!("{}-{}", shift(range.begin), shift(range.end)))
+        .collect();
+    let ids: Vec<String> = ids.iter().map(|&id| shift(id).to_string()).collect();
+    format!("{}\n\n{}\n", ranges.join("\n"), ids.join("\n"))
+}
+
+/// The figures of the report: the ranges, the IDs and the values of both.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    vec![
+        ("fresh ranges", input.ranges.len().to_string()),
+        ("available IDs", input.ids.len().to_string()),
+        (
+            "range bounds",
+            report::span(
+                input
+                    .ranges
+                    .iter()
+                    .flat_map(|range| [range.begin, range.end]),
+            ),
+        ),
+        (
+            "range sizes",
+            report::span(input.ranges.iter().map(Range::size)),
+        ),
+        ("IDs", report::span(input.ids.iter().copied())),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 5,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: None,
+        commands: COMMANDS,
+        shrink: &[SECTION_LINES],
+        strategies: STRATEGIES,
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
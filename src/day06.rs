@@ -0,0 +1,233 @@
+//! Day 6: Trash Compactor.
+//!
+//! ```
+//! use aoc_2025::day06::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +\n");
+//! assert_eq!(solve_part1(&input), 4277556);
+//! assert_eq!(solve_part2(&input), 3263827);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    input::Trim,
+    numeric::checked,
+    parse::{lines, parse, separated_pair, tag, take_while1},
+    report,
+    runner::Day,
+    shrink::Structure,
+};
+
+pub type Int = u64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Mul,
+}
+
+impl Operator {
+    pub fn apply(self, a: Int, b: Int) -> Int {
+        match self {
+            Operator::Add => checked(a.checked_add(b), "problem sum"),
+            Operator::Mul => checked(a.checked_mul(b), "problem product"),
+        }
+    }
+}
+
+/// The worksheet, with the operands of each problem kept as the columns of
+/// text they occupy, so that both readings of the digits remain possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a> {
+    pub operands: Vec<Vec<&'a str>>,
+    pub operators: Vec<Operator>,
+}
+
+pub fn parse_input(input: &str) -> Input<'_> {
+    let grammar = separated_pair(
+        lines(take_while1("a digit", |c| c.is_ascii_digit() || c == ' ')),
+        tag("\n"),
+        take_while1("`+` or `*`", |c| c == '+' || c == '*' || c == ' '),
+    );
+    let (rows, operator_row) = parse(input, &grammar);
+
+    let ops_with_starts: Vec<_> = operator_row
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_whitespace())
+        .collect();
+
+    let operators = ops_with_starts
+        .iter()
+        .map(|(_, c)| match c {
+            '+' => Operator::Add,
+            '*' => Operator::Mul,
+            _ => unreachable!(),
+        })
+        .collect();
+
+    let operands = rows
+        .iter()
+        .map(|line| {
+            let column =
+                |begin: usize, end: usize| &line[begin.min(line.len())..end.min(line.len())];
+            let mut tokens: Vec<_> = ops_with_starts
+                .windows(2)
+                .map(|s| column(s[0].0, s[1].0 - 1))
+                .collect();
+
+            tokens.push(column(ops_with_starts.last().unwrap().0, line.len()));
+            tokens
+        })
+        .collect();
+
+    Input {
+        operands,
+        operators,
+    }
+}
+
+/// Solves the problems reading the operands row by row.
+pub fn solve_part1(input: &Input) -> Int {
+    input
+        .operators
+        .iter()
+        .enumerate()
+        .map(|(i, op)| {
+            input
+                .operands
+                .iter()
+                .map(|n| n[i].trim().parse().unwrap())
+                .reduce(|a, b| op.apply(a, b))
+                .unwrap()
+        })
+        .fold(0, |acc: Int, x| checked(acc.checked_add(x), "grand total"))
+}
+
+/// Solves the problems reading the operands column by column.
+pub fn solve_part2(input: &Input) -> Int {
+    input
+        .operators
+        .iter()
+        .enumerate()
+        .map(|(i, op)| {
+            let operand_count = input.operands.iter().map(|s| s[i].len()).max().unwrap();
+            (0..operand_count)
+                .map(|j| {
+                    let mut res: Int = 0;
+                    for digit in input
+                        .operands
+                        .iter()
+                        .map(|s| s[i].as_bytes().get(j).copied().unwrap_or(b' '))
+                    {
+                        if digit != b' ' {
+                            res = checked(
+                                res.checked_mul(10)
+                                    .and_then(|res| res.checked_add((digit - b'0') as Int)),
+                                "operand",
+                            );
+                        }
+                    }
+                    res
+                })
+                .reduce(|a, b| op.apply(a, b))
+                .unwrap()
+        })
+        .fold(0, |acc: Int, x| checked(acc.checked_add(x), "grand total"))
+}
+
+/// Shrinks the inputs problem by problem, keeping the columns of the
+/// remaining ones aligned.
+const PROBLEMS: Structure = Structure {
+    count: |input| parse_input(input).operators.len(),
+    keep: |input, kept| {
+        let input = parse_input(input);
+        let width = |i: usize| {
+            input
+                .operands
+                .iter()
+                .map(|row| row[i].len())
+                .max()
+                .unwrap_or(1)
+        };
+        let mut rows: Vec<String> = input
+            .operands
+            .iter()
+            .map(|row| {
+                let blocks: Vec<String> = kept
+                    .iter()
+                    .map(|&i| format!("{:1$}", row[i], width(i)))
+                    .collect();
+                blocks.join(" ")
+            })
+            .collect();
+        let operators: Vec<String> = kept
+            .iter()
+            .map(|&i| {
+                let operator = match input.operators[i] {
+                    Operator::Add => "+",
+                    Operator::Mul => "*",
+                };
+                format!("{operator:width$}", width = width(i))
+            })
+            .collect();
+        rows.push(operators.join(" "));
+        rows.iter()
+            .map(|row| format!("{}\n", row.trim_end()))
+            .collect()
+    },
+};
+
+/// Shuffles the problems, whose results add up in any order.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let order = rng.permutation((PROBLEMS.count)(input));
+    (PROBLEMS.keep)(input, &order)
+}
+
+/// The figures of the report: the problems, their operations and their
+/// operands.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    let additions = input
+        .operators
+        .iter()
+        .filter(|&&operator| operator == Operator::Add)
+        .count();
+    let operands = input
+        .operands
+        .iter()
+        .flatten()
+        .map(|operand| operand.trim());
+    vec![
+        ("problems", input.operators.len().to_string()),
+        ("additions", additions.to_string()),
+        (
+            "multiplications",
+            (input.operators.len() - additions).to_string(),
+        ),
+        ("operands per problem", input.operands.len().to_string()),
+        (
+            "operand digits",
+            report::span(operands.clone().map(str::len)),
+        ),
+        (
+            "operands",
+            report::span(operands.filter_map(|operand| operand.parse::<Int>().ok())),
+        ),
+    ]
+}
+
+pub fn day() -> Day<Input<'static>> {
+    Day {
+        number: 6,
+        trim: Trim::End,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: None,
+        commands: &[],
+        shrink: &[PROBLEMS],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,158 @@
+//! Day 7: Laboratories.
+//!
+//! ```
+//! use aoc_2025::day07::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("..S..\n.....\n..^..\n.....\n.^.^.\n");
+//! assert_eq!(solve_part1(&input), 3);
+//! assert_eq!(solve_part2(&input).to_string(), "4");
+//! ```
+
+use crate::{
+    anonymize::{self, Rng},
+    input::Trim,
+    numeric::Count,
+    parse::{lines, parse, take_while1},
+    report,
+    runner::Day,
+    shrink::LINES,
+};
+use std::collections::{HashMap, HashSet, hash_map::Entry};
+
+/// The column of the beam source and the columns of the splitters of each
+/// row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub start: usize,
+    pub splitters: Vec<HashSet<usize>>,
+}
+
+pub fn parse_input(input: &str) -> Input {
+    let rows = parse(
+        input,
+        &lines(take_while1("`.`, `^` or `S`", |c| {
+            matches!(c, '.' | '^' | 'S')
+        })),
+    );
+    let start = rows[0]
+        .as_bytes()
+        .iter()
+        .position(|&c| c == b'S')
+        .expect("the first row has no beam source");
+
+    let splitters = rows
+        .iter()
+        .map(|line| {
+            line.as_bytes()
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &c)| if c == b'^' { Some(i) } else { None })
+                .collect()
+        })
+        .collect();
+
+    Input { start, splitters }
+}
+
+/// Counts the times a beam is split.
+pub fn solve_part1(input: &Input) -> usize {
+    let mut beams = HashSet::<usize>::new();
+    beams.insert(input.start);
+
+    let mut result: usize = 0;
+    for row in &input.splitters {
+        let mut new_beams = HashSet::new();
+        for beam in beams {
+            if row.contains(&beam) {
+                new_beams.insert(beam - 1);
+                new_beams.insert(beam + 1);
+                result += 1;
+            } else {
+                new_beams.insert(beam);
+            }
+        }
+        beams = new_beams;
+    }
+    result
+}
+
+fn increment(map: &mut HashMap<usize, Count>, key: usize, value: &Count) {
+    match map.entry(key) {
+        Entry::Occupied(mut occupied_entry) => {
+            *occupied_entry.get_mut() += value;
+        }
+        Entry::Vacant(vacant_entry) => {
+            vacant_entry.insert(value.clone());
+        }
+    }
+}
+
+/// Counts the timelines a single particle can follow through the splitters.
+pub fn solve_part2(input: &Input) -> Count {
+    let mut beams = HashMap::<usize, Count>::new();
+    beams.insert(input.start, Count::from(1));
+
+    for row in &input.splitters {
+        let mut new_beams = HashMap::new();
+        for beam in beams {
+            if row.contains(&beam.0) {
+                increment(&mut new_beams, beam.0 - 1, &beam.1);
+                increment(&mut new_beams, beam.0 + 1, &beam.1);
+            } else {
+                increment(&mut new_beams, beam.0, &beam.1);
+            }
+        }
+        beams = new_beams;
+    }
+
+    beams.into_values().sum()
+}
+
+/// Mirrors the manifold from left to right, which the beams follow along.
+/// The beams only fall, so there is nothing for the seed to choose.
+fn anonymize(input: &str, _: &mut Rng) -> String {
+    let rows: Vec<&str> = input.lines().collect();
+    let rows = anonymize::reorient(&rows, false, false, true);
+    rows.join("\n") + "\n"
+}
+
+/// The figures of the report: the rows of the manifold and their splitters.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    vec![
+        ("rows", input.splitters.len().to_string()),
+        ("source column", input.start.to_string()),
+        (
+            "splitters",
+            input
+                .splitters
+                .iter()
+                .map(HashSet::len)
+                .sum::<usize>()
+                .to_string(),
+        ),
+        (
+            "splitters per row",
+            report::span(input.splitters.iter().map(HashSet::len)),
+        ),
+        (
+            "splitter columns",
+            report::span(input.splitters.iter().flatten().copied()),
+        ),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 7,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: None,
+        commands: &[],
+        shrink: &[LINES],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,293 @@
+//! Day 8: Playground.
+//!
+//! ```
+//! use aoc_2025::day08::{parse_input, solve_part2};
+//!
+//! let input = parse_input("0,0,0\n10,0,0\n1,0,0\n");
+//! assert_eq!(solve_part2(&input), 10);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    config::{self, Config},
+    input::Trim,
+    parse::{lines, map, number, pair, parse, separated_pair, tag, terminated},
+    repl::{self, Command},
+    report,
+    runner::Day,
+    shrink::LINES,
+};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+pub type Coord = i32;
+pub type Loc = [Coord; 3];
+pub type SquaredDistance = u64;
+
+/// The positions of the junction boxes.
+pub type Input = Vec<Loc>;
+
+pub fn parse_input(input: &str) -> Input {
+    let loc = map(
+        pair(
+            terminated(number(), tag(",")),
+            separated_pair(number(), tag(","), number()),
+        ),
+        |(x, (y, z))| [x, y, z],
+    );
+    parse(input, &lines(loc))
+}
+
+pub fn squared_distance(a: &Loc, b: &Loc) -> SquaredDistance {
+    a.iter()
+        .zip(b.iter())
+        .map(|(u, v)| {
+            (u - v).unsigned_abs() as SquaredDistance * (u - v).unsigned_abs() as SquaredDistance
+        })
+        .sum()
+}
+
+/// A pair of junction boxes that may be linked, ordered so that the closest
+/// pair is the greatest, and among pairs as close the one with the lowest
+/// locations.
+///
+/// ```
+/// use aoc_2025::day08::Candidate;
+///
+/// let pair = |locations| Candidate { locations, distance: 1 };
+/// let (low, high) = (pair([[0, 0, 0], [0, 0, 1]]), pair([[5, 5, 5], [5, 5, 6]]));
+/// assert!(low > high);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub locations: [Loc; 2],
+    pub distance: SquaredDistance,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .cmp(&self.distance)
+            .then_with(|| other.locations.cmp(&self.locations))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Gathers every pair of locations in a heap yielding the closest first.
+pub fn create_candidates(locations: &[Loc]) -> BinaryHeap<Candidate> {
+    locations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| {
+            locations[i + 1..].iter().map(|b| Candidate {
+                locations: [*a.min(b), *a.max(b)],
+                distance: squared_distance(a, b),
+            })
+        })
+        .collect()
+}
+
+/// Puts every location in a circuit of its own.
+pub fn create_initial_circuits(locations: &[Loc]) -> Vec<Vec<Loc>> {
+    locations.iter().map(|&v| vec![v]).collect()
+}
+
+/// Links the closest remaining pair, merging their circuits, and returns
+/// the pair.
+///
+/// # Panics
+///
+/// Panics if there is no candidate left.
+pub fn create_link(
+    circuits: &mut Vec<Vec<Loc>>,
+    candidates: &mut BinaryHeap<Candidate>,
+) -> [Loc; 2] {
+    let linked_locations = candidates.pop().unwrap().locations;
+    let old_circuit_index = circuits
+        .iter()
+        .position(|circuit| circuit.contains(&linked_locations[0]))
+        .unwrap();
+    if !circuits[old_circuit_index].contains(&linked_locations[1]) {
+        let mut old_circuit = circuits.remove(old_circuit_index);
+        let new_circuit = circuits
+            .iter_mut()
+            .find(|circuit| circuit.contains(&linked_locations[1]))
+            .unwrap();
+        new_circuit.append(&mut old_circuit);
+    }
+    linked_locations
+}
+
+/// How many links part 1 makes, and how many of the largest circuits it
+/// multiplies the sizes of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub links: usize,
+    pub top: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            links: 1000,
+            top: 3,
+        }
+    }
+}
+
+impl Params {
+    /// The parameters of the `day08` table of the configuration, falling
+    /// back to the puzzle's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the puzzle cannot take them, which [`config::install`]
+    /// rules out.
+    pub fn configured() -> Self {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The parameters of the `day08` table of `config`, or why they do not
+    /// parse.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let default = Self::default();
+        Ok(Self {
+            links: config.try_param(8, "links")?.unwrap_or(default.links),
+            top: config.try_param(8, "top")?.unwrap_or(default.top),
+        })
+    }
+}
+
+/// Multiplies the sizes of the three largest circuits after 1000 links.
+pub fn solve_part1(input: &Input) -> usize {
+    solve_part1_with(input, &Params::default())
+}
+
+/// # Panics
+///
+/// Panics if there are fewer pairs of boxes than links to make.
+pub fn solve_part1_with(input: &Input, params: &Params) -> usize {
+    let mut candidates = create_candidates(input);
+    let mut circuits = create_initial_circuits(input);
+
+    for _ in 0..params.links {
+        create_link(&mut circuits, &mut candidates);
+    }
+
+    let mut circuit_sizes: Vec<_> = circuits.into_iter().map(|c| c.len()).collect();
+    if params.top < circuit_sizes.len() {
+        circuit_sizes.select_nth_unstable_by(params.top, |a, b| usize::cmp(b, a));
+    }
+    circuit_sizes.iter().take(params.top).product::<usize>()
+}
+
+/// Multiplies the X coordinates of the pair whose link joins all the boxes
+/// in a single circuit.
+pub fn solve_part2(input: &Input) -> SquaredDistance {
+    let mut candidates = create_candidates(input);
+    let mut circuits = create_initial_circuits(input);
+
+    let last_link = loop {
+        let linked_locations = create_link(&mut circuits, &mut candidates);
+        if circuits.len() == 1 {
+            break linked_locations;
+        }
+    };
+
+    last_link[0][0] as SquaredDistance * last_link[1][0] as SquaredDistance
+}
+
+fn circuits_after(input: &Input, args: &[&str]) -> Result<String, String> {
+    let links: usize = repl::arg(args[0], "link count")?;
+    let mut candidates = create_candidates(input);
+    if links > candidates.len() {
+        return Err(format!("there are only {} pairs", candidates.len()));
+    }
+    let mut circuits = create_initial_circuits(input);
+    for _ in 0..links {
+        create_link(&mut circuits, &mut candidates);
+    }
+    let mut sizes: Vec<_> = circuits.iter().map(|c| c.len()).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    let largest: Vec<String> = sizes.iter().take(3).map(|s| s.to_string()).collect();
+    Ok(format!(
+        "{} circuit(s), largest {}",
+        circuits.len(),
+        largest.join(", ")
+    ))
+}
+
+const COMMANDS: &[Command<Input>] = &[Command {
+    pattern: "circuits after {} links",
+    help: "circuits formed by the N shortest links",
+    run: circuits_after,
+}];
+
+/// Shuffles the boxes, then mirrors, shifts and swaps their Y and Z
+/// coordinates, which moves them without changing their distances. The X
+/// coordinates stay, since part 2 multiplies them.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let mut boxes = parse_input(input);
+    rng.shuffle(&mut boxes);
+    for axis in [1, 2] {
+        let low = boxes.iter().map(|loc| loc[axis]).min().unwrap_or(0);
+        let high = boxes.iter().map(|loc| loc[axis]).max().unwrap_or(0);
+        let mirror = rng.coin();
+        let to = rng.between(
+            (low / 2).max(0) as u64,
+            low.saturating_mul(2).max(0).min(Coord::MAX - (high - low)) as u64,
+        ) as Coord;
+        for loc in &mut boxes {
+            let offset = if mirror {
+                high - loc[axis]
+            } else {
+                loc[axis] - low
+            };
+            loc[axis] = to + offset;
+        }
+    }
+    if rng.coin() {
+        boxes.iter_mut().for_each(|loc| loc.swap(1, 2));
+    }
+    boxes
+        .iter()
+        .map(|[x, y, z]| format!("{x},{y},{z}\n"))
+        .collect()
+}
+
+/// The figures of the report: the boxes, the space they spread over and the
+/// links of part 1.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    vec![
+        ("boxes", input.len().to_string()),
+        (
+            "pairs",
+            (input.len() * input.len().saturating_sub(1) / 2).to_string(),
+        ),
+        ("links", Params::configured().links.to_string()),
+        ("X", report::span(input.iter().map(|loc| loc[0]))),
+        ("Y", report::span(input.iter().map(|loc| loc[1]))),
+        ("Z", report::span(input.iter().map(|loc| loc[2]))),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 8,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1_with(input, &Params::configured()).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: None,
+        commands: COMMANDS,
+        shrink: &[LINES],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
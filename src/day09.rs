@@ -0,0 +1,538 @@
+//! Day 9: Movie Theater.
+//!
+//! ```
+//! use aoc_2025::day09::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n");
+//! assert_eq!(solve_part1(&input), 50);
+//! assert_eq!(solve_part2(&input), 24);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    input::Trim,
+    parse::{lines, map, number, parse, separated_pair, tag},
+    report,
+    runner::{Day, Strategy},
+    shrink::Structure,
+};
+
+pub type Coord = i32;
+pub type Loc = [Coord; 2];
+/// The red tiles, in the order they are joined to form a loop.
+pub type Input = Vec<Loc>;
+
+/// Reads the red tiles.
+///
+/// # Panics
+///
+/// Panics if two tiles that follow each other around the loop are not in
+/// the same row or column.
+pub fn parse_input(input: &str) -> Input {
+    let loc = map(separated_pair(number(), tag(","), number()), |(x, y)| {
+        [x, y]
+    });
+    let tiles = parse(input, &lines(loc));
+    for (a, b) in tiles.iter().zip(tiles.iter().cycle().skip(1)) {
+        assert!(
+            a[0] == b[0] || a[1] == b[1],
+            "tiles {},{} and {},{} are not in the same row or column",
+            a[0],
+            a[1],
+            b[0],
+            b[1]
+        );
+    }
+    tiles
+}
+
+pub type Volume = u64;
+
+/// The number of tiles of the rectangle with opposite corners `a` and `b`.
+pub fn enclosed_rectangle_volume(a: &Loc, b: &Loc) -> Volume {
+    ((a[0] - b[0]).abs() + 1) as Volume * ((a[1] - b[1]).abs() + 1) as Volume
+}
+
+/// The largest rectangle with red tiles at two opposite corners.
+pub fn solve_part1(input: &Input) -> Volume {
+    input
+        .iter()
+        .flat_map(|a| input.iter().map(|b| enclosed_rectangle_volume(a, b)))
+        .max()
+        .unwrap()
+}
+
+/// The winding of the loop of tiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Clockwise,
+    CounterClockwise,
+}
+
+pub fn compute_rotation(nodes: &[Loc]) -> Rotation {
+    let y_max = nodes.iter().map(|loc| loc[1]).max().unwrap();
+    let y_max_index = nodes.iter().position(|loc| loc[1] == y_max).unwrap();
+    let xs = [
+        nodes[y_max_index][0],
+        nodes[(y_max_index + 1) % nodes.len()][0],
+    ];
+    if xs[0] < xs[1] {
+        Rotation::Clockwise
+    } else {
+        Rotation::CounterClockwise
+    }
+}
+
+/// The side of a segment that faces the outside of the loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+/// A straight side of the loop, running along `coordinate` from `low` to
+/// `high`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub direction: Direction,
+    pub coordinate: Coord,
+    pub low: Coord,
+    pub high: Coord,
+}
+
+pub fn compute_segment(rotation: Rotation, begin: &Loc, end: &Loc) -> Segment {
+    if begin[0] == end[0] {
+        let coordinate = begin[0];
+        if begin[1] < end[1] {
+            let direction = match rotation {
+                Rotation::Clockwise => Direction::Down,
+                Rotation::CounterClockwise => Direction::Up,
+            };
+            Segment {
+                direction,
+                coordinate,
+                low: begin[1],
+                high: end[1],
+            }
+        } else {
+            let direction = match rotation {
+                Rotation::Clockwise => Direction::Up,
+                Rotation::CounterClockwise => Direction::Down,
+            };
+            Segment {
+                direction,
+                coordinate,
+                low: end[1],
+                high: begin[1],
+            }
+        }
+    } else {
+        let coordinate = begin[1];
+        if begin[0] < end[0] {
+            let direction = match rotation {
+                Rotation::Clockwise => Direction::Left,
+                Rotation::CounterClockwise => Direction::Right,
+            };
+            Segment {
+                direction,
+                coordinate,
+                low: begin[0],
+                high: end[0],
+            }
+        } else {
+            let direction = match rotation {
+                Rotation::Clockwise => Direction::Right,
+                Rotation::CounterClockwise => Direction::Left,
+            };
+            Segment {
+                direction,
+                coordinate,
+                low: end[0],
+                high: begin[0],
+            }
+        }
+    }
+}
+
+pub fn compute_segments(nodes: &[Loc]) -> Vec<Segment> {
+    let rotation = compute_rotation(nodes);
+    let mut result: Vec<Segment> = nodes
+        .windows(2)
+        .map(|ns| compute_segment(rotation, &ns[0], &ns[1]))
+        .collect();
+    result.push(compute_segment(
+        rotation,
+        &nodes[nodes.len() - 1],
+        &nodes[0],
+    ));
+    result
+}
+
+/// A run of tiles just outside a side of the loop, in the column
+/// (`vertical`) or row `coordinate`, from `low` to `high`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub vertical: bool,
+    pub coordinate: Coord,
+    pub low: Coord,
+    pub high: Coord,
+}
+
+/// The tiles along the outer side of each segment that lie on no segment
+/// themselves, which are outside the loop. Where two sides run next to each
+/// other with no tile between them, the tiles beside one are the other.
+pub fn compute_outside_runs(segments: &[Segment]) -> Vec<Run> {
+    let is_vertical =
+        |segment: &Segment| matches!(segment.direction, Direction::Up | Direction::Down);
+    let mut result = Vec::new();
+    for segment in segments {
+        let vertical = is_vertical(segment);
+        let coordinate = match segment.direction {
+            Direction::Down | Direction::Right => segment.coordinate - 1,
+            Direction::Up | Direction::Left => segment.coordinate + 1,
+        };
+        let mut covered: Vec<[Coord; 2]> = segments
+            .iter()
+            .filter_map(|other| {
+                if is_vertical(other) == vertical {
+                    (other.coordinate == coordinate).then_some([other.low, other.high])
+                } else {
+                    (other.low..=other.high)
+                        .contains(&coordinate)
+                        .then_some([other.coordinate, other.coordinate])
+                }
+            })
+            .collect();
+        covered.sort_unstable();
+        let mut low = segment.low;
+        for [begin, end] in covered {
+            if begin > low {
+                result.push(Run {
+                    vertical,
+                    coordinate,
+                    low,
+                    high: (begin - 1).min(segment.high),
+                });
+            }
+            low = low.max(end + 1);
+            if low > segment.high {
+                break;
+            }
+        }
+        if low <= segment.high {
+            result.push(Run {
+                vertical,
+                coordinate,
+                low,
+                high: segment.high,
+            });
+        }
+    }
+    result
+}
+
+/// Whether the rectangle misses the run of tiles outside the loop.
+pub fn is_compatible_rectangle(
+    run: &Run,
+    x_min: Coord,
+    x_max: Coord,
+    y_min: Coord,
+    y_max: Coord,
+) -> bool {
+    let ([across_min, across_max], [along_min, along_max]) = if run.vertical {
+        ([x_min, x_max], [y_min, y_max])
+    } else {
+        ([y_min, y_max], [x_min, x_max])
+    };
+    run.coordinate < across_min
+        || run.coordinate > across_max
+        || run.high < along_min
+        || run.low > along_max
+}
+
+/// The largest rectangle with red tiles at two opposite corners that lies
+/// within the loop.
+pub fn solve_part2(input: &Input) -> Volume {
+    largest_inside_by_grid(input)
+}
+
+/// Solves part 2 checking every rectangle against the tiles outside every
+/// side of the loop.
+///
+/// ```
+/// use aoc_2025::day09::{largest_inside_by_segments, parse_input};
+///
+/// // Two sides run next to each other from 1293,137 to 1294,283.
+/// let input = parse_input(
+///     "1425,4\n1294,4\n1294,283\n1293,283\n1293,137\n1196,137\n1196,382\n1425,382\n",
+/// );
+/// assert_eq!(largest_inside_by_segments(&input), 56580);
+/// ```
+pub fn largest_inside_by_segments(input: &Input) -> Volume {
+    let runs = compute_outside_runs(&compute_segments(input));
+    input
+        .iter()
+        .flat_map(|a| {
+            input.iter().filter_map(|b| {
+                if runs.iter().all(|run| {
+                    is_compatible_rectangle(
+                        run,
+                        a[0].min(b[0]),
+                        a[0].max(b[0]),
+                        a[1].min(b[1]),
+                        a[1].max(b[1]),
+                    )
+                }) {
+                    Some(enclosed_rectangle_volume(a, b))
+                } else {
+                    None
+                }
+            })
+        })
+        .max()
+        .unwrap()
+}
+
+/// The distinct values a coordinate takes at the corners, and the row or
+/// column of the compressed grid standing for each. A gap between values
+/// that are not adjacent gets a row or column of its own, and the grid has
+/// a margin of one all around.
+struct Axis {
+    values: Vec<Coord>,
+    cells: Vec<usize>,
+    len: usize,
+}
+
+impl Axis {
+    fn new(values: impl Iterator<Item = Coord>) -> Self {
+        let mut values: Vec<Coord> = values.collect();
+        values.sort_unstable();
+        values.dedup();
+        let mut cells = Vec::with_capacity(values.len());
+        let mut cell = 0;
+        for (i, &value) in values.iter().enumerate() {
+            cell += if i > 0 && value - values[i - 1] > 1 {
+                2
+            } else {
+                1
+            };
+            cells.push(cell);
+        }
+        Axis {
+            values,
+            cells,
+            len: cell + 2,
+        }
+    }
+
+    fn cell(&self, value: Coord) -> usize {
+        self.cells[self.values.binary_search(&value).unwrap()]
+    }
+}
+
+/// Solves part 2 on a grid compressed to the coordinates of the corners:
+/// the loop is drawn on it, the outside is flooded from the margin, and a
+/// rectangle lies within the loop when a prefix sum counts no outside cell
+/// in it.
+pub fn largest_inside_by_grid(input: &Input) -> Volume {
+    let xs = Axis::new(input.iter().map(|loc| loc[0]));
+    let ys = Axis::new(input.iter().map(|loc| loc[1]));
+    let (width, height) = (xs.len, ys.len);
+    let cell = |loc: &Loc| [xs.cell(loc[0]), ys.cell(loc[1])];
+
+    let mut border = vec![false; width * height];
+    for (a, b) in input.iter().zip(input.iter().cycle().skip(1)) {
+        let [ax, ay] = cell(a);
+        let [bx, by] = cell(b);
+        for y in ay.min(by)..=ay.max(by) {
+            for x in ax.min(bx)..=ax.max(bx) {
+                border[y * width + x] = true;
+            }
+        }
+    }
+
+    let mut outside = vec![false; width * height];
+    outside[0] = true;
+    let mut stack = vec![0];
+    while let Some(index) = stack.pop() {
+        let (x, y) = (index % width, index / width);
+        let neighbours = [
+            (x > 0).then(|| index - 1),
+            (x + 1 < width).then(|| index + 1),
+            (y > 0).then(|| index - width),
+            (y + 1 < height).then(|| index + width),
+        ];
+        for next in neighbours.into_iter().flatten() {
+            if !border[next] && !outside[next] {
+                outside[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+
+    // sums[y][x] counts the outside cells above and left of (x, y).
+    let stride = width + 1;
+    let mut sums = vec![0; stride * (height + 1)];
+    for y in 0..height {
+        for x in 0..width {
+            sums[(y + 1) * stride + x + 1] = usize::from(outside[y * width + x])
+                + sums[y * stride + x + 1]
+                + sums[(y + 1) * stride + x]
+                - sums[y * stride + x];
+        }
+    }
+    let outside_cells = |[ax, ay]: [usize; 2], [bx, by]: [usize; 2]| {
+        let ([x0, x1], [y0, y1]) = ([ax.min(bx), ax.max(bx) + 1], [ay.min(by), ay.max(by) + 1]);
+        sums[y1 * stride + x1] + sums[y0 * stride + x0]
+            - sums[y0 * stride + x1]
+            - sums[y1 * stride + x0]
+    };
+
+    input
+        .iter()
+        .flat_map(|a| {
+            input
+                .iter()
+                .filter(|b| outside_cells(cell(a), cell(b)) == 0)
+                .map(|b| enclosed_rectangle_volume(a, b))
+        })
+        .max()
+        .unwrap()
+}
+
+/// Removes the tiles that repeat the previous one or lie on the straight
+/// line between their neighbours, which are no corners of the loop.
+fn corners(mut tiles: Vec<Loc>) -> Vec<Loc> {
+    loop {
+        let len = tiles.len();
+        let redundant = (0..len).find(|&i| {
+            let [a, b, c] = [tiles[(i + len - 1) % len], tiles[i], tiles[(i + 1) % len]];
+            a == b || (a[0] == b[0] && b[0] == c[0]) || (a[1] == b[1] && b[1] == c[1])
+        });
+        match redundant {
+            Some(i) if len > 2 => tiles.remove(i),
+            _ => return tiles,
+        };
+    }
+}
+
+/// Whether the loop neither crosses nor touches itself, which the puzzle
+/// takes for granted.
+fn is_simple(tiles: &[Loc]) -> bool {
+    let len = tiles.len();
+    let sides: Vec<[Loc; 2]> = (0..len).map(|i| [tiles[i], tiles[(i + 1) % len]]).collect();
+    let meet = |[a, b]: [Loc; 2], [c, d]: [Loc; 2]| {
+        (0..2).all(|axis| {
+            a[axis].min(b[axis]) <= c[axis].max(d[axis])
+                && c[axis].min(d[axis]) <= a[axis].max(b[axis])
+        })
+    };
+    len >= 4
+        && (0..len).all(|i| {
+            (i + 2..len)
+                .filter(|&j| (j + 1) % len != i)
+                .all(|j| !meet(sides[i], sides[j]))
+        })
+}
+
+/// Shrinks the inputs tile by tile, joining the tiles either side of a gap
+/// with a corner so that the loop stays made of rows and columns.
+const TILES: Structure = Structure {
+    count: |input| parse_input(input).len(),
+    keep: |input, kept| {
+        let tiles = parse_input(input);
+        let kept: Vec<Loc> = kept.iter().map(|&i| tiles[i]).collect();
+        let mut joined = Vec::new();
+        for (a, b) in kept.iter().zip(kept.iter().cycle().skip(1)) {
+            joined.push(*a);
+            if a[0] != b[0] && a[1] != b[1] {
+                joined.push([b[0], a[1]]);
+            }
+        }
+        let tiles = corners(joined);
+        if !is_simple(&tiles) {
+            return String::new();
+        }
+        tiles.iter().map(|[x, y]| format!("{x},{y}\n")).collect()
+    },
+};
+
+const STRATEGIES: &[Strategy<Input>] = &[
+    Strategy {
+        name: "compressed-grid",
+        help: "checks rectangles against a flooded grid of the corner coordinates",
+        part1: None,
+        part2: Some(|input| largest_inside_by_grid(input).to_string()),
+    },
+    Strategy {
+        name: "brute-force",
+        help: "checks every rectangle against the tiles outside every side of the loop",
+        part1: None,
+        part2: Some(|input| largest_inside_by_segments(input).to_string()),
+    },
+];
+
+/// Turns, flips and shifts the loop, and starts or walks it from elsewhere,
+/// none of which changes the rectangles inside it.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let mut tiles = parse_input(input);
+    for axis in [0, 1] {
+        let low = tiles.iter().map(|loc| loc[axis]).min().unwrap_or(0);
+        let high = tiles.iter().map(|loc| loc[axis]).max().unwrap_or(0);
+        let mirror = rng.coin();
+        let to = rng.between(
+            (low / 2).max(0) as u64,
+            low.saturating_mul(2).max(0).min(Coord::MAX - (high - low)) as u64,
+        ) as Coord;
+        for loc in &mut tiles {
+            let offset = if mirror {
+                high - loc[axis]
+            } else {
+                loc[axis] - low
+            };
+            loc[axis] = to + offset;
+        }
+    }
+    if rng.coin() {
+        tiles.iter_mut().for_each(|loc| loc.swap(0, 1));
+    }
+    if rng.coin() {
+        tiles.reverse();
+    }
+    let start = rng.below(tiles.len().max(1) as u64) as usize;
+    tiles.rotate_left(start);
+    tiles.iter().map(|[x, y]| format!("{x},{y}\n")).collect()
+}
+
+/// The figures of the report: the tiles, the area they spread over and the
+/// way the loop winds.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    let winding = match compute_rotation(input) {
+        Rotation::Clockwise => "clockwise",
+        Rotation::CounterClockwise => "counter-clockwise",
+    };
+    vec![
+        ("red tiles", input.len().to_string()),
+        ("X", report::span(input.iter().map(|loc| loc[0]))),
+        ("Y", report::span(input.iter().map(|loc| loc[1]))),
+        ("winding", winding.to_string()),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 9,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: None,
+        commands: &[],
+        shrink: &[TILES],
+        strategies: STRATEGIES,
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,385 @@
+//! Day 10: Factory.
+//!
+//! ```
+//! use aoc_2025::day10::{parse_input, solve_part1, solve_part2};
+//!
+//! let input = parse_input("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n");
+//! assert_eq!(solve_part1(&input), 2);
+//! assert_eq!(solve_part2(&input), 10);
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    input::Trim,
+    parse::{
+        Parser, delimited, lines, many, map, number, pair, parse, preceded, separated, tag,
+        take_while1,
+    },
+    repl::{self, Command},
+    report,
+    runner::Day,
+    shrink::{LINES, Structure},
+    stream::{Streaming, stream},
+};
+
+pub type Joltage = u16;
+
+/// A machine: its indicator lights, the lights or counters each button
+/// acts on, and the joltage each counter requires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub lights: Vec<bool>,
+    pub buttons: Vec<Vec<usize>>,
+    pub requirements: Vec<Joltage>,
+}
+
+pub type Input = Vec<Machine>;
+
+pub fn parse_machine(line: &str) -> Machine {
+    parse(line, &machine())
+}
+
+/// A machine such as `[.##.] (3) (1,3) {3,5,4}`.
+fn machine<'a>() -> impl Parser<'a, Machine> {
+    let lights = delimited(
+        tag("["),
+        take_while1("`.` or `#`", |c| c == '.' || c == '#'),
+        tag("]"),
+    );
+    let button = delimited(tag("("), separated(number(), tag(",")), tag(")"));
+    let requirements = delimited(tag("{"), separated(number(), tag(",")), tag("}"));
+    map(
+        pair(
+            lights,
+            pair(
+                many(preceded(tag(" "), button)),
+                preceded(tag(" "), requirements),
+            ),
+        ),
+        |(lights, (buttons, requirements))| Machine {
+            lights: lights.bytes().map(|b| b == b'#').collect(),
+            buttons,
+            requirements,
+        },
+    )
+}
+
+pub fn parse_input(input: &str) -> Input {
+    parse(input, &lines(machine()))
+}
+
+type Binary = u16;
+
+fn bools_to_binary_impl(bools: impl Iterator<Item = bool>) -> Binary {
+    bools.fold(0, |acc, x| 2 * acc + if x { 1 } else { 0 })
+}
+
+fn bools_to_binary(bools: &[bool]) -> Binary {
+    bools_to_binary_impl(bools.iter().copied().rev())
+}
+
+fn indices_to_binary(indices: &[usize]) -> Binary {
+    indices.iter().map(|&i| 1 << i).sum::<Binary>()
+}
+
+fn contains(combination: Binary, index: usize) -> bool {
+    (combination >> index) % 2 == 1
+}
+
+fn compute_valid_combinations(buttons: &[Binary], lights: Binary) -> Vec<Binary> {
+    (0..(1 << buttons.len()))
+        .filter(|&combination| {
+            let actual = (0..buttons.len())
+                .filter(|&b| contains(combination, b))
+                .fold(0, |acc, x| acc ^ buttons[x]);
+            actual == lights
+        })
+        .collect()
+}
+
+/// The fewest button presses that turn the indicator lights on as shown.
+pub fn solve_lights(machine: &Machine) -> usize {
+    compute_valid_combinations(
+        &machine
+            .buttons
+            .iter()
+            .map(|b| indices_to_binary(b))
+            .collect::<Vec<_>>(),
+        bools_to_binary(&machine.lights),
+    )
+    .into_iter()
+    .map(|candidate| candidate.count_ones() as usize)
+    .min()
+    .unwrap()
+}
+
+pub fn solve_part1(input: &Input) -> usize {
+    input.iter().map(solve_lights).sum::<usize>()
+}
+
+fn compute_lights(requirements: &[Joltage]) -> Binary {
+    bools_to_binary_impl(requirements.iter().rev().map(|&j| j % 2 == 1))
+}
+
+fn compute_delta(
+    requirements: &[Joltage],
+    buttons: &[Binary],
+    combination: Binary,
+) -> Vec<Joltage> {
+    (0..buttons.len())
+        .filter(|&b| contains(combination, b))
+        .fold(vec![0; requirements.len()], |mut acc, b| {
+            for (index, count) in acc.iter_mut().enumerate() {
+                if contains(buttons[b], index) {
+                    *count += 1;
+                }
+            }
+            acc
+        })
+}
+
+fn compute_new_requirements(
+    requirements: &[Joltage],
+    buttons: &[Binary],
+    combination: Binary,
+) -> Option<Vec<Joltage>> {
+    let delta = compute_delta(requirements, buttons, combination);
+    if delta.iter().zip(requirements.iter()).any(|(d, r)| d > r) {
+        None
+    } else {
+        Some(
+            delta
+                .iter()
+                .zip(requirements.iter())
+                .map(|(&d, &r)| r - d)
+                .collect(),
+        )
+    }
+}
+
+fn solve_joltage_impl(requirements: &[Joltage], buttons: &[Binary]) -> Option<usize> {
+    if requirements.iter().all(|&r| r == 0) {
+        return Some(0);
+    }
+    let combinations = compute_valid_combinations(buttons, compute_lights(requirements));
+    combinations
+        .into_iter()
+        .filter_map(|combination| {
+            compute_new_requirements(requirements, buttons, combination).and_then(|nr| {
+                solve_joltage_impl(&nr.iter().map(|v| v / 2).collect::<Vec<_>>(), buttons)
+                    .map(|rec| 2 * rec + combination.count_ones() as usize)
+            })
+        })
+        .min()
+}
+
+/// The fewest button presses that bring every counter to its required
+/// joltage.
+pub fn solve_joltage(machine: &Machine) -> usize {
+    solve_joltage_impl(
+        &machine.requirements,
+        &machine
+            .buttons
+            .iter()
+            .map(|b| indices_to_binary(b))
+            .collect::<Vec<_>>(),
+    )
+    .unwrap()
+}
+
+pub fn solve_part2(input: &Input) -> usize {
+    input.iter().map(solve_joltage).sum::<usize>()
+}
+
+fn press(input: &Input, args: &[&str]) -> Result<String, String> {
+    let (index, buttons) = args.split_first().ok_or("expected a machine and buttons")?;
+    let index: usize = repl::arg(index, "machine")?;
+    let machine = input
+        .get(index)
+        .ok_or_else(|| format!("there are only {} machines", input.len()))?;
+    let mut lights = vec![false; machine.lights.len()];
+    let mut joltages = vec![0; machine.requirements.len()];
+    for button in buttons {
+        let button: usize = repl::arg(button, "button")?;
+        let wiring = machine
+            .buttons
+            .get(button)
+            .ok_or_else(|| format!("machine {index} has {} buttons", machine.buttons.len()))?;
+        for &light in wiring {
+            lights[light] = !lights[light];
+            joltages[light] += 1;
+        }
+    }
+    let lights: String = lights
+        .iter()
+        .map(|&on| if on { '#' } else { '.' })
+        .collect();
+    let joltages: Vec<String> = joltages.iter().map(Joltage::to_string).collect();
+    let target: String = machine
+        .lights
+        .iter()
+        .map(|&on| if on { '#' } else { '.' })
+        .collect();
+    Ok(format!(
+        "[{lights}] {{{}}}{}",
+        joltages.join(","),
+        if lights == target {
+            ", lights match"
+        } else {
+            ""
+        }
+    ))
+}
+
+const COMMANDS: &[Command<Input>] = &[Command {
+    pattern: "press {} {}...",
+    help: "lights and joltages after pressing buttons of a machine",
+    run: press,
+}];
+
+/// Both answers, accumulated one machine at a time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub part1: usize,
+    pub part2: usize,
+}
+
+impl Streaming for Tally {
+    type Line = Machine;
+
+    fn parse_line(line: &str) -> Machine {
+        parse_machine(line)
+    }
+
+    fn push(&mut self, machine: Machine) {
+        self.part1 += solve_lights(&machine);
+        self.part2 += solve_joltage(&machine);
+    }
+
+    fn answers(&self) -> [String; 2] {
+        [self.part1.to_string(), self.part2.to_string()]
+    }
+}
+
+/// Writes a machine back as it reads, with only some of its buttons.
+fn write_machine<'a>(machine: &Machine, buttons: impl Iterator<Item = &'a Vec<usize>>) -> String {
+    let list = |numbers: &mut dyn Iterator<Item = String>| numbers.collect::<Vec<_>>().join(",");
+    let mut line: String = "[".to_string();
+    line.extend(machine.lights.iter().map(|&on| if on { '#' } else { '.' }));
+    line.push(']');
+    for button in buttons {
+        line += &format!(" ({})", list(&mut button.iter().map(usize::to_string)));
+    }
+    line += &format!(
+        " {{{}}}\n",
+        list(&mut machine.requirements.iter().map(Joltage::to_string))
+    );
+    line
+}
+
+/// Once whole machines are dropped, shrinks the inputs button by button.
+const BUTTONS: Structure = Structure {
+    count: |input| {
+        parse_input(input)
+            .iter()
+            .map(|machine| machine.buttons.len())
+            .sum()
+    },
+    keep: |input, kept| {
+        let mut index = 0;
+        parse_input(input)
+            .iter()
+            .map(|machine| {
+                let buttons = machine.buttons.iter().filter(|_| {
+                    index += 1;
+                    kept.binary_search(&(index - 1)).is_ok()
+                });
+                write_machine(machine, buttons)
+            })
+            .collect()
+    },
+};
+
+/// Shuffles the machines and their buttons, and renumbers the lights and
+/// counters of each machine, which the buttons follow.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let mut machines = parse_input(input);
+    rng.shuffle(&mut machines);
+    let mut output = String::new();
+    for machine in &machines {
+        let order = rng.permutation(machine.lights.len());
+        let mut lights = vec![false; order.len()];
+        let mut requirements = vec![0; machine.requirements.len()];
+        for (i, &to) in order.iter().enumerate() {
+            lights[to] = machine.lights[i];
+            if let Some(&requirement) = machine.requirements.get(i) {
+                requirements[to] = requirement;
+            }
+        }
+        let mut buttons: Vec<Vec<usize>> = machine
+            .buttons
+            .iter()
+            .map(|button| {
+                let mut button: Vec<usize> = button.iter().map(|&i| order[i]).collect();
+                button.sort_unstable();
+                button
+            })
+            .collect();
+        rng.shuffle(&mut buttons);
+        let machine = Machine {
+            lights,
+            buttons,
+            requirements,
+        };
+        output += &write_machine(&machine, machine.buttons.iter());
+    }
+    output
+}
+
+/// The figures of the report: the machines, their lights and buttons and the
+/// joltages they require.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    vec![
+        ("machines", input.len().to_string()),
+        (
+            "lights",
+            report::span(input.iter().map(|machine| machine.lights.len())),
+        ),
+        (
+            "buttons",
+            report::span(input.iter().map(|machine| machine.buttons.len())),
+        ),
+        (
+            "lights per button",
+            report::span(
+                input
+                    .iter()
+                    .flat_map(|machine| machine.buttons.iter().map(Vec::len)),
+            ),
+        ),
+        (
+            "requirements",
+            report::span(
+                input
+                    .iter()
+                    .flat_map(|machine| machine.requirements.iter().copied()),
+            ),
+        ),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 10,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: Some(|input| solve_part2(input).to_string()),
+        stream: Some(stream::<Tally>),
+        commands: COMMANDS,
+        shrink: &[LINES, BUTTONS],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
@@ -0,0 +1,365 @@
+//! Day 11: Reactor.
+//!
+//! ```
+//! use aoc_2025::{day11::{count_paths, parse_input}, numeric::Count};
+//!
+//! let input = parse_input("you: a b\na: out\nb: a out\n");
+//! assert_eq!(count_paths(&input, "you", "out", &[]), Count::from(3));
+//! assert_eq!(count_paths(&input, "you", "out", &["b"]), Count::from(2));
+//! ```
+
+use crate::{
+    anonymize::Rng,
+    config::{self, Config},
+    input::Trim,
+    numeric::Count,
+    parse::{lines, many, pair, parse, preceded, tag, take_while1, terminated},
+    repl::Command,
+    report,
+    runner::Day,
+    shrink::{LINES, Structure},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::identity,
+};
+
+pub type Device<'a> = &'a str;
+
+/// The outputs of each device.
+pub type Input<'a> = HashMap<Device<'a>, Vec<Device<'a>>>;
+
+pub fn parse_input(input: &str) -> Input<'_> {
+    let device = || take_while1("a device name", |c| c.is_ascii_alphanumeric());
+    let line = pair(
+        terminated(device(), tag(":")),
+        many(preceded(tag(" "), device())),
+    );
+    parse(input, &lines(line)).into_iter().collect()
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Status<'a> {
+    node: Device<'a>,
+    visited_steps: Vec<bool>,
+}
+
+impl<'a> Status<'a> {
+    fn new(root: Device<'a>, visited_step_count: usize) -> Self {
+        Status {
+            node: root,
+            visited_steps: vec![false; visited_step_count],
+        }
+    }
+}
+
+fn solve_dfs<'a>(
+    status: Status<'a>,
+    target: Device<'a>,
+    steps: &[Device<'a>],
+    graph: &Input<'a>,
+    memory: &mut HashMap<Status<'a>, Count>,
+) -> Count {
+    if let Some(result) = memory.get(&status) {
+        result.clone()
+    } else {
+        let result = solve_dfs_impl(status.clone(), target, steps, graph, memory);
+        memory.insert(status, result.clone());
+        result
+    }
+}
+
+fn solve_dfs_impl<'a>(
+    status: Status<'a>,
+    target: Device<'a>,
+    steps: &[Device<'a>],
+    graph: &Input<'a>,
+    memory: &mut HashMap<Status<'a>, Count>,
+) -> Count {
+    if status.node == target {
+        if status.visited_steps.iter().copied().all(identity) {
+            Count::from(1)
+        } else {
+            Count::zero()
+        }
+    } else {
+        let Status {
+            node,
+            mut visited_steps,
+        } = status;
+        if let Some(index) = steps.iter().position(|&step| step == node) {
+            visited_steps[index] = true;
+        }
+        graph
+            .get(node)
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|next| {
+                solve_dfs(
+                    Status {
+                        node: next,
+                        visited_steps: visited_steps.clone(),
+                    },
+                    target,
+                    steps,
+                    graph,
+                    memory,
+                )
+            })
+            .sum()
+    }
+}
+
+/// Counts the paths from `from` to `to` that go through every device of
+/// `steps`, in any order.
+pub fn count_paths<'a>(
+    graph: &Input<'a>,
+    from: Device<'a>,
+    to: Device<'a>,
+    steps: &[Device<'a>],
+) -> Count {
+    let mut memory: HashMap<Status, Count> = HashMap::new();
+    solve_dfs(
+        Status::new(from, steps.len()),
+        to,
+        steps,
+        graph,
+        &mut memory,
+    )
+}
+
+/// The devices the paths of each part run between, and those the paths of
+/// part 2 must go through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    pub part1_from: String,
+    pub part2_from: String,
+    pub to: String,
+    pub via: Vec<String>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_from: "you".to_string(),
+            part2_from: "svr".to_string(),
+            to: "out".to_string(),
+            via: vec!["dac".to_string(), "fft".to_string()],
+        }
+    }
+}
+
+impl Params {
+    /// The parameters of the `day11` table of the configuration, falling
+    /// back to the puzzle's.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the puzzle cannot take them, which [`config::install`]
+    /// rules out.
+    pub fn configured() -> Self {
+        Self::from_config(config::current()).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// The parameters of the `day11` table of `config`, or why they do not
+    /// parse.
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        let default = Self::default();
+        Ok(Self {
+            part1_from: config
+                .try_param(11, "part1_from")?
+                .unwrap_or(default.part1_from),
+            part2_from: config
+                .try_param(11, "part2_from")?
+                .unwrap_or(default.part2_from),
+            to: config.try_param(11, "to")?.unwrap_or(default.to),
+            via: config.try_list(11, "via")?.unwrap_or(default.via),
+        })
+    }
+}
+
+/// Counts the paths from `you` to `out`.
+pub fn solve_part1(input: &Input) -> Count {
+    solve_part1_with(input, &Params::default())
+}
+
+pub fn solve_part1_with(input: &Input, params: &Params) -> Count {
+    count_paths(input, &params.part1_from, &params.to, &[])
+}
+
+/// Counts the paths from `svr` to `out` that go through `dac` and `fft`.
+pub fn solve_part2(input: &Input) -> Count {
+    solve_part2_with(input, &Params::default())
+}
+
+pub fn solve_part2_with(input: &Input, params: &Params) -> Count {
+    let via: Vec<Device> = params.via.iter().map(String::as_str).collect();
+    count_paths(input, &params.part2_from, &params.to, &via)
+}
+
+fn device<'a>(input: &Input<'a>, name: &str) -> Result<Device<'a>, String> {
+    input
+        .iter()
+        .flat_map(|(device, outputs)| std::iter::once(device).chain(outputs))
+        .find(|&&device| device == name)
+        .copied()
+        .ok_or_else(|| format!("unknown device: {name}"))
+}
+
+fn paths(input: &Input, args: &[&str]) -> Result<String, String> {
+    let from = device(input, args[0])?;
+    let to = device(input, args[1])?;
+    Ok(count_paths(input, from, to, &[]).to_string())
+}
+
+const COMMANDS: &[Command<Input<'static>>] = &[Command {
+    pattern: "paths {} {}",
+    help: "number of paths between two devices",
+    run: paths,
+}];
+
+/// Once whole devices are dropped, shrinks the inputs connection by
+/// connection, keeping every device even when it is left without outputs.
+const CONNECTIONS: Structure = Structure {
+    count: |input| {
+        input
+            .lines()
+            .map(|line| line.split_whitespace().count().saturating_sub(1))
+            .sum()
+    },
+    keep: |input, kept| {
+        let mut index = 0;
+        input
+            .lines()
+            .map(|line| {
+                let mut words = line.split_whitespace();
+                let device = words.next().unwrap_or_default();
+                let outputs: String = words
+                    .filter(|_| {
+                        index += 1;
+                        kept.binary_search(&(index - 1)).is_ok()
+                    })
+                    .map(|output| format!(" {output}"))
+                    .collect();
+                format!("{device}{outputs}\n")
+            })
+            .collect()
+    },
+};
+
+/// How many names of a pattern are tried before it grows longer.
+const NAME_TRIES: u32 = 64;
+
+/// Renames the devices but those the parts run between or through, keeping
+/// the kind of each character of their names, and shuffles the devices and
+/// their outputs.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let params = Params::configured();
+    let special: HashSet<&str> = [&params.part1_from, &params.part2_from, &params.to]
+        .into_iter()
+        .chain(&params.via)
+        .map(String::as_str)
+        .collect();
+    let mut names: HashMap<&str, String> = special.iter().map(|&d| (d, d.to_string())).collect();
+    let mut taken: HashSet<String> = names.values().cloned().collect();
+    let mut lines: Vec<Vec<&str>> = input
+        .lines()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    rng.shuffle(&mut lines);
+    let mut output = String::new();
+    for line in &mut lines {
+        if let Some((_, outputs)) = line.split_first_mut() {
+            rng.shuffle(outputs);
+        }
+        for (i, device) in line.iter().enumerate() {
+            let device = device.trim_end_matches(':');
+            let name = names.entry(device).or_insert_with(|| {
+                // Short patterns run out of names, so a pattern that misses
+                // too often grows a lowercase letter.
+                let mut pattern = device.to_string();
+                for tries in 1.. {
+                    let name: String = pattern
+                        .chars()
+                        .map(|c| {
+                            let (first, count) = match c {
+                                '0'..='9' => (b'0', 10),
+                                'A'..='Z' => (b'A', 26),
+                                _ => (b'a', 26),
+                            };
+                            (first + rng.below(count) as u8) as char
+                        })
+                        .collect();
+                    if taken.insert(name.clone()) {
+                        return name;
+                    }
+                    if tries % NAME_TRIES == 0 {
+                        pattern.push('a');
+                    }
+                }
+                unreachable!()
+            });
+            output += &if i == 0 {
+                format!("{name}:")
+            } else {
+                format!(" {name}")
+            };
+        }
+        output.push('\n');
+    }
+    output
+}
+
+/// The figures of the report: the size of the graph of devices.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    let devices: HashSet<Device> = input
+        .iter()
+        .flat_map(|(&device, outputs)| std::iter::once(device).chain(outputs.iter().copied()))
+        .collect();
+    vec![
+        ("devices", devices.len().to_string()),
+        ("devices with outputs", input.len().to_string()),
+        (
+            "connections",
+            input.values().map(Vec::len).sum::<usize>().to_string(),
+        ),
+        (
+            "outputs per device",
+            report::span(input.values().map(Vec::len)),
+        ),
+    ]
+}
+
+pub fn day() -> Day<Input<'static>> {
+    Day {
+        number: 11,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1_with(input, &Params::configured()).to_string(),
+        part2: Some(|input| solve_part2_with(input, &Params::configured()).to_string()),
+        stream: None,
+        commands: COMMANDS,
+        shrink: &[LINES, CONNECTIONS],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anonymizing_outgrows_short_names() {
+        // Every one-character name is taken, so the last ones to be renamed
+        // are likely to miss until their pattern grows.
+        let input: String = ('a'..='z')
+            .chain('0'..='9')
+            .map(|device| format!("{device}: out\n"))
+            .collect();
+        let anonymized = anonymize(&input, &mut Rng::new(1));
+        assert_eq!(parse_input(&anonymized).len(), 36);
+    }
+}
@@ -0,0 +1,229 @@
+//! Day 12: Christmas Tree Farm.
+//!
+//! ```
+//! use aoc_2025::day12::{parse_input, solve_part1};
+//!
+//! let input = parse_input("0:\n##\n#.\n\n2x2: 1\n1x2: 1\n");
+//! assert_eq!(solve_part1(&input), 1);
+//! ```
+
+use crate::{
+    anonymize::{self, Rng},
+    input::{self, Trim},
+    parse::{
+        lines, many, map, number, pair, parse, preceded, sections, separated_pair, tag,
+        take_while1, terminated,
+    },
+    report,
+    runner::Day,
+    shrink::Structure,
+};
+use std::collections::HashSet;
+
+pub type Coord = i32;
+pub type Loc = [Coord; 2];
+/// The cells a present occupies.
+pub type Brick = HashSet<Loc>;
+
+/// An area under a tree and how many presents of each shape it must hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub size: Loc,
+    pub requirements: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub bricks: Vec<Brick>,
+    pub regions: Vec<Region>,
+}
+
+pub fn parse_input(input: &str) -> Input {
+    let shape = preceded(
+        terminated(number::<usize>(), tag(":\n")),
+        lines(take_while1("`#` or `.`", |c| c == '#' || c == '.')),
+    );
+    let region = map(
+        pair(
+            separated_pair(number(), tag("x"), number()),
+            preceded(tag(":"), many(preceded(tag(" "), number()))),
+        ),
+        |((x, y), requirements)| Region {
+            size: [x, y],
+            requirements,
+        },
+    );
+    let (shapes, regions) = parse(
+        input,
+        &separated_pair(sections(shape), tag("\n\n"), lines(region)),
+    );
+
+    let bricks = shapes
+        .iter()
+        .map(|rows| {
+            rows.iter()
+                .enumerate()
+                .flat_map(|(i, row)| {
+                    row.chars().enumerate().filter_map(move |(j, c)| {
+                        if c == '#' {
+                            Some([i as Coord, j as Coord])
+                        } else {
+                            None
+                        }
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    Input { bricks, regions }
+}
+
+/// Counts the regions large enough for the total area of their presents.
+pub fn solve_part1(input: &Input) -> usize {
+    let counts: Vec<_> = input.bricks.iter().map(|b| b.len()).collect();
+    input
+        .regions
+        .iter()
+        .filter(|&region| {
+            let volume_min = region
+                .requirements
+                .iter()
+                .zip(counts.iter())
+                .map(|(r, c)| r * c)
+                .sum::<usize>();
+            volume_min <= (region.size[0] as usize * region.size[1] as usize)
+        })
+        .count()
+}
+
+/// The shapes of an input, without their index, and its regions.
+fn shapes_and_regions(input: &str) -> (Vec<&str>, Vec<&str>) {
+    let sections = input::sections(input);
+    match sections.split_last() {
+        Some((regions, shapes)) => (
+            shapes
+                .iter()
+                .map(|shape| shape.split_once('\n').map_or("", |(_, rows)| rows))
+                .collect(),
+            regions.lines().collect(),
+        ),
+        None => (Vec::new(), Vec::new()),
+    }
+}
+
+fn write_input(shapes: &[&str], regions: &[String]) -> String {
+    let mut input = String::new();
+    for (index, shape) in shapes.iter().enumerate() {
+        input += &format!("{index}:\n{shape}\n\n");
+    }
+    input + &regions.join("\n") + "\n"
+}
+
+/// Shrinks the inputs shape by shape, renumbering the remaining shapes and
+/// dropping the dropped ones from the requirements.
+const SHAPES: Structure = Structure {
+    count: |input| shapes_and_regions(input).0.len(),
+    keep: |input, kept| {
+        let (shapes, regions) = shapes_and_regions(input);
+        let shapes: Vec<&str> = kept.iter().map(|&i| shapes[i]).collect();
+        let regions: Vec<String> = regions
+            .iter()
+            .map(|region| {
+                let (size, requirements) = region.split_once(':').unwrap_or((region, ""));
+                let requirements: Vec<&str> = requirements.split_whitespace().collect();
+                let requirements: String = kept
+                    .iter()
+                    .filter_map(|&i| requirements.get(i))
+                    .map(|count| format!(" {count}"))
+                    .collect();
+                format!("{size}:{requirements}")
+            })
+            .collect();
+        write_input(&shapes, &regions)
+    },
+};
+
+/// Shrinks the inputs region by region.
+const REGIONS: Structure = Structure {
+    count: |input| shapes_and_regions(input).1.len(),
+    keep: |input, kept| {
+        let (shapes, regions) = shapes_and_regions(input);
+        let regions: Vec<String> = kept.iter().map(|&i| regions[i].to_string()).collect();
+        write_input(&shapes, &regions)
+    },
+};
+
+/// Shuffles the shapes along with the requirements for them, turns or flips
+/// each of them, shuffles the regions and turns some of them.
+fn anonymize(input: &str, rng: &mut Rng) -> String {
+    let (shapes, _) = shapes_and_regions(input);
+    let order = rng.permutation(shapes.len());
+    let shapes: Vec<String> = order
+        .iter()
+        .map(|&i| {
+            let rows: Vec<&str> = shapes[i].lines().collect();
+            anonymize::reorient(&rows, rng.coin(), rng.coin(), rng.coin()).join("\n")
+        })
+        .collect();
+    let mut regions: Vec<String> = parse_input(input)
+        .regions
+        .iter()
+        .map(|region| {
+            let [width, length] = region.size;
+            let (width, length) = if rng.coin() {
+                (length, width)
+            } else {
+                (width, length)
+            };
+            let requirements: String = order
+                .iter()
+                .filter_map(|&i| region.requirements.get(i))
+                .map(|count| format!(" {count}"))
+                .collect();
+            format!("{width}x{length}:{requirements}")
+        })
+        .collect();
+    rng.shuffle(&mut regions);
+    let shapes: Vec<&str> = shapes.iter().map(String::as_str).collect();
+    write_input(&shapes, &regions)
+}
+
+/// The figures of the report: the shapes, the regions and the presents
+/// they must hold.
+fn stats(input: &Input) -> Vec<(&'static str, String)> {
+    let presents = input
+        .regions
+        .iter()
+        .map(|region| region.requirements.iter().sum::<usize>());
+    vec![
+        ("shapes", input.bricks.len().to_string()),
+        (
+            "cells per shape",
+            report::span(input.bricks.iter().map(HashSet::len)),
+        ),
+        ("regions", input.regions.len().to_string()),
+        (
+            "region sides",
+            report::span(input.regions.iter().flat_map(|region| region.size)),
+        ),
+        ("presents", presents.clone().sum::<usize>().to_string()),
+        ("presents per region", report::span(presents)),
+    ]
+}
+
+pub fn day() -> Day<Input> {
+    Day {
+        number: 12,
+        trim: Trim::Both,
+        parse: parse_input,
+        part1: |input| solve_part1(input).to_string(),
+        part2: None,
+        stream: None,
+        commands: &[],
+        shrink: &[SHAPES, REGIONS],
+        strategies: &[],
+        anonymize: Some(anonymize),
+        stats,
+    }
+}
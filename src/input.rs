@@ -0,0 +1,208 @@
+//! Clean-up of the raw inputs before a day parses them: line endings, a
+//! byte order mark, whitespace around the lines and blank lines at either
+//! end, along with a report of such irregularities for inputs that do not
+//! parse.
+
+/// How much whitespace [`normalize`] strips from each line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trim {
+    /// Keep the lines as they are, for inputs where columns are significant.
+    None,
+    /// Strip trailing whitespace only, keeping the leading indentation.
+    End,
+    /// Strip leading and trailing whitespace.
+    Both,
+}
+
+/// Converts line endings to `\n`, drops a leading byte order mark, trims each
+/// line according to `trim` and removes blank lines at both ends of the
+/// input. The result ends with a single newline unless it is empty.
+pub fn normalize(input: &str, trim: Trim) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines: Vec<&str> = input
+        .split('\n')
+        .map(|line| normalize_line(line, trim))
+        .collect();
+    let is_blank = |line: &&str| line.trim().is_empty();
+    let begin = lines.iter().position(|line| !is_blank(line));
+    let end = lines.iter().rposition(|line| !is_blank(line));
+    match (begin, end) {
+        (Some(begin), Some(end)) => {
+            let mut result = lines[begin..=end].join("\n");
+            result.push('\n');
+            result
+        }
+        _ => String::new(),
+    }
+}
+
+/// Strips the line ending of a single line and trims it according to `trim`.
+pub fn normalize_line(line: &str, trim: Trim) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    match trim {
+        Trim::None => line,
+        Trim::End => line.trim_end(),
+        Trim::Both => line.trim(),
+    }
+}
+
+/// Splits the input into sections separated by one or more blank lines.
+/// Lines holding only whitespace, including a lone `\r`, count as blank.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut section: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let begin = offset;
+        offset += line.len();
+        if line.trim().is_empty() {
+            if let Some((begin, end)) = section.take() {
+                result.push(&input[begin..end]);
+            }
+        } else {
+            let end = begin + line.trim_end_matches(['\n', '\r']).len();
+            section = Some((section.map_or(begin, |(begin, _)| begin), end));
+        }
+    }
+    if let Some((begin, end)) = section {
+        result.push(&input[begin..end]);
+    }
+    result
+}
+
+type Check = (&'static str, fn(&str) -> bool);
+
+fn content(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+fn describe(what: &str, lines: &[usize]) -> Option<String> {
+    match lines {
+        [] => None,
+        [line] => Some(format!("{what} on line {line}")),
+        [first, ..] => Some(format!(
+            "{what} on {} lines, first on line {first}",
+            lines.len()
+        )),
+    }
+}
+
+/// Lists the irregularities of a raw input that [`normalize`] would smooth
+/// over or that a parser is likely to trip on.
+pub fn anomalies(input: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    if input.is_empty() {
+        result.push("input is empty".to_string());
+        return result;
+    }
+    if input.starts_with('\u{feff}') {
+        result.push("byte order mark at the start of the input".to_string());
+    }
+
+    let lines: Vec<&str> = input.split_inclusive('\n').collect();
+    let checks: [Check; 5] = [
+        ("CRLF line ending", |line| line.ends_with("\r\n")),
+        ("stray carriage return", |line| content(line).contains('\r')),
+        ("trailing whitespace", |line| {
+            let line = content(line);
+            !line.trim().is_empty() && line.trim_end() != line
+        }),
+        ("tab character", |line| line.contains('\t')),
+        ("non-ASCII character", |line| {
+            !line.trim_start_matches('\u{feff}').is_ascii()
+        }),
+    ];
+    for (what, predicate) in checks {
+        let numbers: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| predicate(line))
+            .map(|(i, _)| i + 1)
+            .collect();
+        result.extend(describe(what, &numbers));
+    }
+
+    let blank: Vec<bool> = lines.iter().map(|line| line.trim().is_empty()).collect();
+    let leading = blank.iter().take_while(|&&b| b).count();
+    let trailing = blank.iter().rev().take_while(|&&b| b).count();
+    if leading == lines.len() {
+        result.push("input holds only blank lines".to_string());
+        return result;
+    }
+    if leading > 0 {
+        result.push(format!("{leading} blank line(s) at the start"));
+    }
+    if trailing > 0 {
+        result.push(format!("{trailing} blank line(s) at the end"));
+    }
+    let repeated: Vec<usize> = (1..lines.len() - trailing)
+        .filter(|&i| i > leading && blank[i] && blank[i - 1])
+        .map(|i| i + 1)
+        .collect();
+    result.extend(describe("repeated blank line", &repeated));
+    if !input.ends_with('\n') {
+        result.push("no newline at the end of the input".to_string());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_smooths_line_endings_and_blank_lines() {
+        let raw = "\u{feff}\r\n\n  L68 \r\nR48\r\n\r\n\n";
+        assert_eq!(normalize(raw, Trim::None), "  L68 \nR48\n");
+        assert_eq!(normalize(raw, Trim::End), "  L68\nR48\n");
+        assert_eq!(normalize(raw, Trim::Both), "L68\nR48\n");
+        assert_eq!(normalize("L68", Trim::Both), "L68\n");
+        assert_eq!(normalize("", Trim::Both), "");
+        assert_eq!(normalize("\u{feff}\r\n \n", Trim::None), "");
+    }
+
+    #[test]
+    fn normalize_keeps_inner_blank_lines() {
+        assert_eq!(normalize("1-2\r\n\r\n3\r\n", Trim::Both), "1-2\n\n3\n");
+    }
+
+    #[test]
+    fn normalize_line_strips_one_line_ending() {
+        assert_eq!(normalize_line(" a \r\n", Trim::None), " a ");
+        assert_eq!(normalize_line(" a \r\n", Trim::End), " a");
+        assert_eq!(normalize_line(" a \r\n", Trim::Both), "a");
+        assert_eq!(normalize_line("a\n\n", Trim::None), "a\n");
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        assert_eq!(sections("a\nb\n\nc\n"), ["a\nb", "c"]);
+        assert_eq!(sections("\r\na\r\n\r\n \n\r\nb\r\nc"), ["a", "b\r\nc"]);
+        assert_eq!(sections("a"), ["a"]);
+        assert!(sections("\n \n").is_empty());
+    }
+
+    #[test]
+    fn anomalies_point_at_the_lines() {
+        assert_eq!(anomalies(""), ["input is empty"]);
+        assert!(anomalies("L68\nR48\n").is_empty());
+        assert_eq!(
+            anomalies("\u{feff}L68\r\nR48\r\n\n\n"),
+            [
+                "byte order mark at the start of the input",
+                "CRLF line ending on 2 lines, first on line 1",
+                "2 blank line(s) at the end",
+            ]
+        );
+        assert_eq!(
+            anomalies("L68 \n\n\nR48"),
+            [
+                "trailing whitespace on line 1",
+                "repeated blank line on line 3",
+                "no newline at the end of the input",
+            ]
+        );
+    }
+}
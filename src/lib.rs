@@ -0,0 +1,31 @@
+//! Advent of Code 2025: a library module per day, and the tooling that
+//! runs, checks and measures them.
+
+pub mod anonymize;
+pub mod audit;
+pub mod budget;
+pub mod config;
+pub mod dashboard;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod input;
+pub mod numeric;
+pub mod parse;
+pub mod perf;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod shrink;
+pub mod stream;
+pub mod vault;
+pub mod watch;
@@ -0,0 +1,163 @@
+//! The numeric policy of the solvers: arithmetic that may overflow is
+//! checked in every build, failing with an error rather than wrapping, and
+//! the solvers that count paths or timelines use [`Count`], which cannot
+//! overflow at all.
+
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign},
+};
+
+/// Unwraps the result of a checked operation, panicking with an error naming
+/// `what` on overflow whatever the build profile.
+///
+/// ```should_panic
+/// use aoc_2025::numeric::checked;
+///
+/// checked(u8::MAX.checked_add(1), "the sum");
+/// ```
+#[track_caller]
+pub fn checked<T>(result: Option<T>, what: &str) -> T {
+    result.unwrap_or_else(|| panic!("arithmetic overflow in {what}"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    Small(u128),
+    /// Little-endian 64-bit limbs of a value above `u128::MAX`, without
+    /// leading zero limbs.
+    Big(Vec<u64>),
+}
+
+/// An unsigned counter that switches from `u128` to arbitrary precision
+/// when it outgrows it.
+///
+/// ```
+/// use aoc_2025::numeric::Count;
+///
+/// let big = Count::from(u128::MAX) + Count::from(1);
+/// assert_eq!(big.to_string(), "340282366920938463463374607431768211456");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Count(Repr);
+
+impl Count {
+    pub fn zero() -> Self {
+        Count(Repr::Small(0))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == Repr::Small(0)
+    }
+
+    /// The value, if it fits in a `u128`.
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.0 {
+            Repr::Small(value) => Some(value),
+            Repr::Big(_) => None,
+        }
+    }
+
+    fn limbs(&self) -> Vec<u64> {
+        match &self.0 {
+            Repr::Small(value) => vec![*value as u64, (*value >> 64) as u64],
+            Repr::Big(limbs) => limbs.clone(),
+        }
+    }
+
+    fn from_limbs(mut limbs: Vec<u64>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        match limbs[..] {
+            [] => Count::zero(),
+            [low] => Count(Repr::Small(low as u128)),
+            [low, high] => Count(Repr::Small(((high as u128) << 64) | low as u128)),
+            _ => Count(Repr::Big(limbs)),
+        }
+    }
+}
+
+impl Default for Count {
+    fn default() -> Self {
+        Count::zero()
+    }
+}
+
+impl From<u128> for Count {
+    fn from(value: u128) -> Self {
+        Count(Repr::Small(value))
+    }
+}
+
+impl Add<&Count> for &Count {
+    type Output = Count;
+
+    fn add(self, other: &Count) -> Count {
+        if let (Repr::Small(a), Repr::Small(b)) = (&self.0, &other.0)
+            && let Some(sum) = a.checked_add(*b)
+        {
+            return Count(Repr::Small(sum));
+        }
+        let (a, b) = (self.limbs(), other.limbs());
+        let mut limbs = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0;
+        for i in 0..a.len().max(b.len()) {
+            let sum = *a.get(i).unwrap_or(&0) as u128 + *b.get(i).unwrap_or(&0) as u128 + carry;
+            limbs.push(sum as u64);
+            carry = sum >> 64;
+        }
+        limbs.push(carry as u64);
+        Count::from_limbs(limbs)
+    }
+}
+
+impl Add for Count {
+    type Output = Count;
+
+    fn add(self, other: Count) -> Count {
+        &self + &other
+    }
+}
+
+impl AddAssign<&Count> for Count {
+    fn add_assign(&mut self, other: &Count) {
+        *self = &*self + other;
+    }
+}
+
+impl Sum for Count {
+    fn sum<I: Iterator<Item = Count>>(iter: I) -> Count {
+        iter.fold(Count::zero(), |acc, x| &acc + &x)
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = match &self.0 {
+            Repr::Small(value) => return value.fmt(f),
+            Repr::Big(limbs) => limbs.clone(),
+        };
+        // Peel off base 10^19 digits, the largest power of ten in a limb.
+        const BASE: u64 = 10_000_000_000_000_000_000;
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            let mut remainder = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let current = (remainder << 64) | *limb as u128;
+                *limb = (current / BASE as u128) as u64;
+                remainder = current % BASE as u128;
+            }
+            chunks.push(remainder as u64);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+        let mut digits = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:019}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
@@ -0,0 +1,413 @@
+//! Parser combinators for the shapes the inputs keep coming in: numbers,
+//! `a-b` ranges, comma-separated lists, one item per line, blank-line
+//! separated sections and delimited groups.
+//!
+//! A parser is any function taking a [`Cursor`] and returning the value it
+//! read, or `None` after recording what it expected. Lists and alternatives
+//! backtrack freely, and a failed parse reports the furthest position any
+//! parser reached, with everything that could have come next there.
+//!
+//! ```
+//! use aoc_2025::parse::{lines, number, separated_pair, tag, try_parse};
+//!
+//! let ranges = lines(separated_pair(number::<u32>(), tag("-"), number()));
+//! assert_eq!(try_parse("1-2\n3-4\n", &ranges), Ok(vec![(1, 2), (3, 4)]));
+//!
+//! let err = try_parse("1-2\n3x4\n", &ranges).unwrap_err();
+//! assert_eq!(err.to_string(), "line 2, column 2: expected `-`, found `x`");
+//! ```
+
+use std::{fmt, str::FromStr};
+
+/// A position in the input being parsed, which also keeps track of the
+/// furthest failure so far.
+pub struct Cursor<'a> {
+    input: &'a str,
+    offset: usize,
+    furthest: usize,
+    expected: Vec<String>,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    /// Records that `what` was expected at the current position, and fails.
+    pub fn fail<T>(&mut self, what: impl Into<String>) -> Option<T> {
+        if self.offset > self.furthest {
+            self.furthest = self.offset;
+            self.expected.clear();
+        }
+        if self.offset == self.furthest {
+            let what = what.into();
+            if !self.expected.contains(&what) {
+                self.expected.push(what);
+            }
+        }
+        None
+    }
+
+    fn advance(&mut self, bytes: usize) {
+        self.offset += bytes;
+    }
+}
+
+/// Something that reads a `T` from a [`Cursor`].
+pub trait Parser<'a, T>: Fn(&mut Cursor<'a>) -> Option<T> {}
+
+impl<'a, T, F: Fn(&mut Cursor<'a>) -> Option<T>> Parser<'a, T> for F {}
+
+/// Why the input does not parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub column: usize,
+    pub expected: Vec<String>,
+    pub found: String,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected ", self.line, self.column)?;
+        match &self.expected[..] {
+            [] => write!(f, "nothing")?,
+            [what] => write!(f, "{what}")?,
+            [init @ .., last] => write!(f, "{} or {last}", init.join(", "))?,
+        }
+        write!(f, ", found {}", self.found)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses the whole of `input`, up to trailing newlines.
+pub fn try_parse<'a, T>(input: &'a str, parser: &impl Parser<'a, T>) -> Result<T, Error> {
+    let mut cursor = Cursor {
+        input,
+        offset: 0,
+        furthest: 0,
+        expected: Vec::new(),
+    };
+    let value = parser(&mut cursor).and_then(|value| {
+        let newlines = cursor.rest().len() - cursor.rest().trim_start_matches('\n').len();
+        cursor.advance(newlines);
+        if cursor.rest().is_empty() {
+            Some(value)
+        } else {
+            cursor.fail("the end of the input")
+        }
+    });
+    value.ok_or_else(|| {
+        let before = &input[..cursor.furthest];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Error {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: cursor.expected,
+            found: match input[cursor.furthest..].chars().next() {
+                None => "the end of the input".to_string(),
+                Some('\n') => "the end of the line".to_string(),
+                Some(c) => format!("`{c}`"),
+            },
+        }
+    })
+}
+
+/// Parses the whole of `input`, as the days do.
+///
+/// # Panics
+///
+/// Panics with the position of the error if the input does not parse.
+pub fn parse<'a, T>(input: &'a str, parser: &impl Parser<'a, T>) -> T {
+    try_parse(input, parser).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Reads exactly `tag`.
+pub fn tag<'a>(tag: &'static str) -> impl Parser<'a, ()> {
+    move |cursor: &mut Cursor<'a>| {
+        if cursor.rest().starts_with(tag) {
+            cursor.advance(tag.len());
+            Some(())
+        } else {
+            cursor.fail(match tag {
+                "\n" => "the end of the line".to_string(),
+                "\n\n" => "a blank line".to_string(),
+                tag => format!("`{}`", tag.escape_debug()),
+            })
+        }
+    }
+}
+
+/// Reads the longest non-empty run of characters satisfying `predicate`,
+/// described as `what` in errors.
+pub fn take_while1<'a>(
+    what: &'static str,
+    predicate: fn(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    move |cursor: &mut Cursor<'a>| {
+        let rest = cursor.rest();
+        let end = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        if end == 0 {
+            return cursor.fail(what);
+        }
+        cursor.advance(end);
+        Some(&rest[..end])
+    }
+}
+
+/// Reads an integer, with a sign if `T` has one, failing if it does not fit
+/// in a `T`.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |cursor: &mut Cursor<'a>| {
+        let rest = cursor.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        if digits == 0 {
+            return cursor.fail("a number");
+        }
+        match rest[..sign + digits].parse() {
+            Ok(number) => {
+                cursor.advance(sign + digits);
+                Some(number)
+            }
+            Err(_) => cursor.fail(format!(
+                "a number that fits in {}",
+                std::any::type_name::<T>()
+            )),
+        }
+    }
+}
+
+pub fn map<'a, A, B>(parser: impl Parser<'a, A>, f: impl Fn(A) -> B) -> impl Parser<'a, B> {
+    move |cursor: &mut Cursor<'a>| parser(cursor).map(&f)
+}
+
+/// Tries `first`, then `second` from the same position.
+pub fn or<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = cursor.offset;
+        first(cursor).or_else(|| {
+            cursor.offset = start;
+            second(cursor)
+        })
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |cursor: &mut Cursor<'a>| Some((first(cursor)?, second(cursor)?))
+}
+
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    move |cursor: &mut Cursor<'a>| {
+        first(cursor)?;
+        second(cursor)
+    }
+}
+
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    move |cursor: &mut Cursor<'a>| {
+        let value = first(cursor)?;
+        second(cursor)?;
+        Some(value)
+    }
+}
+
+/// Reads `first` and `second` around a separator, such as the bounds of an
+/// `a-b` range.
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |cursor: &mut Cursor<'a>| {
+        let a = first(cursor)?;
+        separator(cursor)?;
+        Some((a, second(cursor)?))
+    }
+}
+
+/// Reads `inner` between an opening and a closing delimiter, such as `(..)`.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    inner: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    move |cursor: &mut Cursor<'a>| {
+        open(cursor)?;
+        let value = inner(cursor)?;
+        close(cursor)?;
+        Some(value)
+    }
+}
+
+/// Reads `item` as many times as it matches, possibly none.
+pub fn many<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut items = Vec::new();
+        loop {
+            let start = cursor.offset;
+            match item(cursor) {
+                Some(value) if cursor.offset > start => items.push(value),
+                _ => {
+                    cursor.offset = start;
+                    return Some(items);
+                }
+            }
+        }
+    }
+}
+
+/// Reads one `item` or more, separated by `separator`. The list ends before
+/// the first separator that is not followed by an item.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut items = vec![item(cursor)?];
+        loop {
+            let start = cursor.offset;
+            match separator(cursor).and_then(|_| item(cursor)) {
+                Some(value) => items.push(value),
+                None => {
+                    cursor.offset = start;
+                    return Some(items);
+                }
+            }
+        }
+    }
+}
+
+/// Reads one `item` per line, and none where only line breaks are left, as
+/// in an empty input.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    or(none, separated(item, tag("\n")))
+}
+
+/// Reads one `item` per section, the sections being separated by a blank
+/// line, and none where only line breaks are left, as in an empty input.
+pub fn sections<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    or(none, separated(item, tag("\n\n")))
+}
+
+/// Reads nothing, as an empty list, at the end of the input only: anywhere
+/// else the item that did not match is the error.
+fn none<'a, T>(cursor: &mut Cursor<'a>) -> Option<Vec<T>> {
+    cursor
+        .rest()
+        .trim_start_matches('\n')
+        .is_empty()
+        .then(Vec::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error<'a, T: std::fmt::Debug>(input: &'a str, parser: &impl Parser<'a, T>) -> String {
+        try_parse(input, parser).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn numbers_take_a_sign_and_must_fit() {
+        assert_eq!(try_parse("-42\n\n", &number::<i32>()), Ok(-42));
+        assert_eq!(try_parse("300", &number::<u16>()), Ok(300));
+        assert_eq!(
+            try_parse("300", &number::<u8>()).unwrap_err().to_string(),
+            "line 1, column 1: expected a number that fits in u8, found `3`"
+        );
+        assert_eq!(
+            try_parse("-", &number::<i32>()).unwrap_err().to_string(),
+            "line 1, column 1: expected a number, found `-`"
+        );
+        assert_eq!(
+            try_parse("12x", &number::<i32>()).unwrap_err().to_string(),
+            "line 1, column 3: expected the end of the input, found `x`"
+        );
+    }
+
+    #[test]
+    fn sequences_and_alternatives() {
+        let group = delimited(tag("("), separated(number::<u32>(), tag(",")), tag(")"));
+        assert_eq!(try_parse("(1,2,3)", &group), Ok(vec![1, 2, 3]));
+        let word = take_while1("a letter", |c| c.is_ascii_alphabetic());
+        let item = or(
+            map(number::<u32>(), |n| n.to_string()),
+            map(word, str::to_uppercase),
+        );
+        assert_eq!(
+            try_parse("ab 12 cd", &separated(item, tag(" "))),
+            Ok(vec!["AB".to_string(), "12".to_string(), "CD".to_string()])
+        );
+        let keyed = pair(
+            terminated(number::<u32>(), tag(":")),
+            preceded(tag(" "), number::<u32>()),
+        );
+        assert_eq!(try_parse("1: 2", &keyed), Ok((1, 2)));
+        assert_eq!(try_parse("", &many(tag("x"))), Ok(vec![]));
+        assert_eq!(try_parse("xxx", &many(tag("x"))), Ok(vec![(), (), ()]));
+    }
+
+    #[test]
+    fn lists_stop_before_a_dangling_separator() {
+        let list = terminated(separated(number::<u32>(), tag(",")), tag(",."));
+        assert_eq!(try_parse("1,2,.", &list), Ok(vec![1, 2]));
+        let blocks = sections(lines(number::<u32>()));
+        assert_eq!(
+            try_parse("1\n2\n\n3\n", &blocks),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn empty_inputs_have_no_lines() {
+        assert_eq!(try_parse("", &lines(number::<u32>())), Ok(vec![]));
+        assert_eq!(
+            try_parse("\n\n", &sections(lines(number::<u32>()))),
+            Ok(vec![])
+        );
+        assert_eq!(
+            try_parse("x\n", &lines(number::<u32>()))
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 1: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn errors_list_everything_expected_at_the_furthest_point() {
+        let sign = or(tag("+"), tag("-"));
+        assert_eq!(
+            error(
+                "1\n2*3",
+                &lines(map(separated(number::<u32>(), sign), drop))
+            ),
+            "line 2, column 2: expected `+`, `-`, the end of the line or the end of the input, found `*`"
+        );
+        let groups = sections(map(lines(tag("ab")), drop));
+        assert_eq!(
+            error("ab\nab\n\nax", &groups),
+            "line 4, column 1: expected `ab` or the end of the input, found `a`"
+        );
+        assert_eq!(
+            error("ab\n\n\nab", &groups),
+            "line 4, column 1: expected the end of the input, found `a`"
+        );
+        assert_eq!(
+            error("", &tag("ab")),
+            "line 1, column 1: expected `ab`, found the end of the input"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use crate::runner::print_table;
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The median timing of one stage of a benchmark run.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub timestamp: u64,
+    pub revision: String,
+    pub machine: String,
+    pub day: u8,
+    pub input: String,
+    pub stage: String,
+    pub median: Duration,
+    pub runs: usize,
+}
+
+pub fn history_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("perf-history.tsv")
+}
+
+fn sanitize(field: &str) -> String {
+    field.replace(['\t', '\n'], " ").trim().to_string()
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The abbreviated commit being measured, suffixed with `-dirty` when the
+/// working tree holds uncommitted changes.
+pub fn revision() -> String {
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(revision) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{revision}-dirty"),
+            _ => revision,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// A short description of the host: its name, CPU model and core count.
+pub fn machine() -> String {
+    let host = fs::read_to_string("/proc/sys/kernel/hostname")
+        .map(|host| host.trim().to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+    let cpu = fs::read_to_string("/proc/cpuinfo")
+        .ok()
+        .and_then(|info| {
+            info.lines()
+                .find(|line| line.starts_with("model name"))
+                .and_then(|line| line.split_once(':'))
+                .map(|(_, model)| model.split_whitespace().collect::<Vec<_>>().join(" "))
+        })
+        .unwrap_or_else(|| std::env::consts::ARCH.to_string());
+    let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+    sanitize(&format!("{host} / {cpu} / {cores} cores"))
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+pub fn median(samples: &mut [Duration]) -> Duration {
+    samples.sort_unstable();
+    samples[samples.len() / 2]
+}
+
+pub fn append(path: &Path, records: &[Record]) -> Result<(), String> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("cannot open {}: {err}", path.display()))?;
+    for record in records {
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            record.timestamp,
+            sanitize(&record.revision),
+            sanitize(&record.machine),
+            record.day,
+            sanitize(&record.input),
+            sanitize(&record.stage),
+            record.median.as_nanos(),
+            record.runs,
+        )
+        .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let mut fields = line.split('\t');
+    let record = Record {
+        timestamp: fields.next()?.parse().ok()?,
+        revision: fields.next()?.to_string(),
+        machine: fields.next()?.to_string(),
+        day: fields.next()?.parse().ok()?,
+        input: fields.next()?.to_string(),
+        stage: fields.next()?.to_string(),
+        median: Duration::from_nanos(fields.next()?.parse().ok()?),
+        runs: fields.next()?.parse().ok()?,
+    };
+    fields.next().is_none().then_some(record)
+}
+
+/// Loads the history, skipping the lines that do not hold a valid record.
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    let history =
+        fs::read_to_string(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    Ok(history.lines().filter_map(parse_record).collect())
+}
+
+/// What the latest run of a stage is compared to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Baseline {
+    Best,
+    Previous,
+}
+
+/// Prints the trend of every day, input and stage, comparing the latest run
+/// with the earlier ones from the same machine. Returns the number of stages
+/// that slowed down by more than `threshold` percent.
+pub fn report(records: &[Record], baseline: Baseline, threshold: f64) -> usize {
+    let mut series: BTreeMap<(u8, &str, &str), Vec<&Record>> = BTreeMap::new();
+    for record in records {
+        series
+            .entry((record.day, &record.input, &record.stage))
+            .or_default()
+            .push(record);
+    }
+
+    let mut rows = Vec::new();
+    let mut regressions = 0;
+    for ((day, input, stage), runs) in series {
+        let (latest, earlier) = runs.split_last().unwrap();
+        let comparable: Vec<&Record> = earlier
+            .iter()
+            .copied()
+            .filter(|record| record.machine == latest.machine)
+            .collect();
+        let reference = match baseline {
+            Baseline::Best => comparable.iter().map(|record| record.median).min(),
+            Baseline::Previous => comparable.last().map(|record| record.median),
+        };
+        let (change, status) = match reference {
+            Some(reference) if !reference.is_zero() => {
+                let change = 100.0 * (latest.median.as_secs_f64() - reference.as_secs_f64())
+                    / reference.as_secs_f64();
+                let status = if change > threshold {
+                    regressions += 1;
+                    "REGRESSION"
+                } else {
+                    "ok"
+                };
+                (format!("{change:+.1}%"), status)
+            }
+            _ => ("-".to_string(), "new"),
+        };
+        let trend: Vec<String> = runs
+            .iter()
+            .rev()
+            .take(5)
+            .rev()
+            .map(|record| format!("{:.2?}", record.median))
+            .collect();
+        rows.push(vec![
+            format!("{day:02}"),
+            input.to_string(),
+            stage.to_string(),
+            latest.revision.clone(),
+            format!("{:.2?}", latest.median),
+            reference.map_or_else(|| "-".to_string(), |reference| format!("{reference:.2?}")),
+            change,
+            trend.join(" > "),
+            status.to_string(),
+        ]);
+    }
+    let against = match baseline {
+        Baseline::Best => "best",
+        Baseline::Previous => "previous",
+    };
+    print_table(
+        &[
+            "day", "input", "stage", "revision", "latest", against, "change", "trend", "status",
+        ],
+        &rows,
+    );
+    regressions
+}
@@ -0,0 +1,349 @@
+use crate::runner::{self, Part};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// A query a day answers about its parsed input.
+///
+/// The pattern is matched word by word against the query: `{}` captures a
+/// single word and a trailing `{}...` captures all the remaining ones.
+pub struct Command<I> {
+    pub pattern: &'static str,
+    pub help: &'static str,
+    pub run: fn(&I, &[&str]) -> Result<String, String>,
+}
+
+/// Parses a captured word, naming it in the error.
+pub fn arg<T: FromStr>(word: &str, what: &str) -> Result<T, String> {
+    word.parse().map_err(|_| format!("invalid {what}: {word}"))
+}
+
+fn captures<'a>(pattern: &str, words: &[&'a str]) -> Option<Vec<&'a str>> {
+    let mut captured = Vec::new();
+    let mut words = words.iter();
+    for token in pattern.split_whitespace() {
+        match token {
+            "{}..." => {
+                captured.extend(words.by_ref());
+                return Some(captured);
+            }
+            "{}" => captured.push(*words.next()?),
+            literal => {
+                if *words.next()? != literal {
+                    return None;
+                }
+            }
+        }
+    }
+    words.next().is_none().then_some(captured)
+}
+
+pub fn history_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("repl-day{day:02}.history"))
+}
+
+pub struct Repl<'a, I> {
+    pub day: u8,
+    pub input: &'a I,
+    pub commands: &'a [Command<I>],
+    pub parts: [Option<Part<I>>; 2],
+    pub history: Vec<String>,
+}
+
+impl<I> Repl<'_, I> {
+    fn help(&self) -> String {
+        let mut lines = vec![
+            "part 1|2              solve a part of the puzzle".to_string(),
+            "history               list the previous queries".to_string(),
+            "!N                    repeat query N of the history".to_string(),
+            "help                  show this message".to_string(),
+            "quit                  leave the session".to_string(),
+        ];
+        lines.extend(
+            self.commands
+                .iter()
+                .map(|command| format!("{:21} {}", command.pattern, command.help)),
+        );
+        lines.join("\n")
+    }
+
+    /// Answers one query, returning `None` when the session should end.
+    pub fn eval(&mut self, line: &str) -> Option<Result<String, String>> {
+        let line = line.trim();
+        if let Some(index) = line.strip_prefix('!') {
+            let entry = index
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| self.history.get(index.checked_sub(1)?).cloned());
+            return match entry {
+                Some(entry) => self.eval(&entry),
+                None => Some(Err(format!("no history entry {index}"))),
+            };
+        }
+        if !line.is_empty() {
+            self.history.push(line.to_string());
+        }
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let result = match words.as_slice() {
+            [] => Ok(String::new()),
+            ["quit"] | ["exit"] => return None,
+            ["help"] => Ok(self.help()),
+            ["history"] => Ok(self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| format!("{:4}  {entry}", i + 1))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            ["part", part] => match part
+                .parse::<usize>()
+                .ok()
+                .and_then(|part| self.parts.get(part.checked_sub(1)?).copied().flatten())
+            {
+                Some(solve) => runner::quietly(|| runner::catch(|| solve(self.input))),
+                None => Err(format!("no part {part}")),
+            },
+            _ => match self
+                .commands
+                .iter()
+                .find_map(|command| Some((command, captures(command.pattern, &words)?)))
+            {
+                Some((command, args)) => {
+                    runner::quietly(|| runner::catch(|| (command.run)(self.input, &args)))
+                        .and_then(|result| result)
+                }
+                None => Err(format!("unknown query: {line}, try help")),
+            },
+        };
+        Some(result)
+    }
+
+    /// Answers the queries read from `reader` into `output` until the
+    /// reader is exhausted or a `quit` query. Scripted sessions echo each
+    /// query before its answer, so that their output reads as a transcript.
+    pub fn run(
+        &mut self,
+        reader: &mut dyn BufRead,
+        output: &mut dyn Write,
+        interactive: bool,
+        echo: bool,
+    ) -> io::Result<()> {
+        let mut line = String::new();
+        loop {
+            if interactive {
+                write!(output, "day{:02}> ", self.day)?;
+                output.flush()?;
+            }
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+            let query = line.trim();
+            if query.is_empty() || query.starts_with('#') {
+                continue;
+            }
+            if echo {
+                writeln!(output, "> {query}")?;
+            }
+            match self.eval(query) {
+                None => break,
+                Some(Ok(answer)) if answer.is_empty() => {}
+                Some(Ok(answer)) => writeln!(output, "{answer}")?,
+                Some(Err(err)) => writeln!(output, "error: {err}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs a session on `input`, reading the queries from `script` when given
+/// and from the standard input otherwise. Sessions on a terminal keep their
+/// queries in a history file, which can itself be replayed as a script.
+pub fn session<I>(
+    day: u8,
+    input: &I,
+    commands: &[Command<I>],
+    parts: [Option<Part<I>>; 2],
+    script: Option<&Path>,
+) -> Result<(), String> {
+    let mut repl = Repl {
+        day,
+        input,
+        commands,
+        parts,
+        history: Vec::new(),
+    };
+    match script {
+        Some(script) => {
+            let file = fs::File::open(script)
+                .map_err(|err| format!("cannot read {}: {err}", script.display()))?;
+            repl.run(
+                &mut io::BufReader::new(file),
+                &mut io::stdout(),
+                false,
+                true,
+            )
+            .map_err(|err| err.to_string())?;
+        }
+        None => {
+            let stdin = io::stdin();
+            if !stdin.is_terminal() {
+                return repl
+                    .run(&mut stdin.lock(), &mut io::stdout(), false, true)
+                    .map_err(|err| err.to_string());
+            }
+            let path = history_path(day);
+            if let Ok(history) = fs::read_to_string(&path) {
+                repl.history = history.lines().map(str::to_string).collect();
+            }
+            let known = repl.history.len();
+            repl.run(&mut stdin.lock(), &mut io::stdout(), true, false)
+                .map_err(|err| err.to_string())?;
+            println!();
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|err| format!("cannot open {}: {err}", path.display()))?;
+            for entry in &repl.history[known..] {
+                writeln!(file, "{entry}")
+                    .map_err(|err| format!("cannot write {}: {err}", path.display()))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMANDS: &[Command<Vec<u32>>] = &[
+        Command {
+            pattern: "count",
+            help: "how many numbers there are",
+            run: |input, _| Ok(input.len().to_string()),
+        },
+        Command {
+            pattern: "number {}",
+            help: "the number at an index",
+            run: |input, args| {
+                let index: usize = arg(args[0], "index")?;
+                Ok(input[index].to_string())
+            },
+        },
+        Command {
+            pattern: "sum {}...",
+            help: "the sum of the numbers at some indices",
+            run: |input, args| {
+                let mut sum = 0;
+                for index in args {
+                    sum += input[arg::<usize>(index, "index")?];
+                }
+                Ok(sum.to_string())
+            },
+        },
+    ];
+
+    fn repl(input: &Vec<u32>) -> Repl<'_, Vec<u32>> {
+        Repl {
+            day: 1,
+            input,
+            commands: COMMANDS,
+            parts: [Some(|input| input.iter().sum::<u32>().to_string()), None],
+            history: Vec::new(),
+        }
+    }
+
+    fn replay(script: &str) -> String {
+        let input = vec![3, 5, 8];
+        let mut output = Vec::new();
+        repl(&input)
+            .run(&mut script.as_bytes(), &mut output, false, true)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn scripts_replay_as_transcripts() {
+        let script = "# a comment\n\
+                      count\n\
+                      \n\
+                      number 2\n\
+                      sum 0 2\n\
+                      part 1\n\
+                      part 2\n\
+                      !2\n\
+                      history\n\
+                      quit\n\
+                      count\n";
+        let transcript = [
+            "> count",
+            "3",
+            "> number 2",
+            "8",
+            "> sum 0 2",
+            "11",
+            "> part 1",
+            "16",
+            "> part 2",
+            "error: no part 2",
+            "> !2",
+            "8",
+            "> history",
+            "   1  count",
+            "   2  number 2",
+            "   3  sum 0 2",
+            "   4  part 1",
+            "   5  part 2",
+            "   6  number 2",
+            "   7  history",
+            "> quit",
+        ];
+        assert_eq!(replay(script), transcript.join("\n") + "\n");
+    }
+
+    #[test]
+    fn queries_that_fail_are_errors() {
+        let input = vec![3, 5, 8];
+        let mut repl = repl(&input);
+        assert_eq!(
+            repl.eval("frobnicate 3"),
+            Some(Err("unknown query: frobnicate 3, try help".to_string()))
+        );
+        assert_eq!(
+            repl.eval("number x"),
+            Some(Err("invalid index: x".to_string()))
+        );
+        assert!(matches!(repl.eval("number 7"), Some(Err(_))));
+        assert_eq!(
+            repl.eval("!99"),
+            Some(Err("no history entry 99".to_string()))
+        );
+        assert_eq!(repl.eval("exit"), None);
+    }
+
+    #[test]
+    fn patterns_capture_words() {
+        let words = |line: &'static str| line.split_whitespace().collect::<Vec<_>>();
+        assert_eq!(captures("count", &words("count")), Some(vec![]));
+        assert_eq!(captures("count", &words("count 2")), None);
+        assert_eq!(captures("number {}", &words("number 2")), Some(vec!["2"]));
+        assert_eq!(captures("number {}", &words("number")), None);
+        assert_eq!(captures("number {}", &words("index 2")), None);
+        assert_eq!(
+            captures("sum {}...", &words("sum 1 2 3")),
+            Some(vec!["1", "2", "3"])
+        );
+        assert_eq!(captures("sum {}...", &words("sum")), Some(vec![]));
+        assert_eq!(
+            captures("paths {} {}", &words("paths you out")),
+            Some(vec!["you", "out"])
+        );
+    }
+}
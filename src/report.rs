@@ -0,0 +1,137 @@
+//! Markdown write-ups of a run of a day, for retrospectives: the figures the
+//! day gives of its input, the answers with their timings, the strategy
+//! that found them and the dashboard's drawing of the input, if any.
+
+use crate::{
+    config, dashboard, perf,
+    runner::{self, Day},
+};
+use std::{
+    fmt::{Display, Write as _},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// The figures describing a parsed input, by name.
+pub type Stats<I> = fn(&I) -> Vec<(&'static str, String)>;
+
+/// The smallest and largest of some values, as `low..=high`.
+///
+/// ```
+/// use aoc_2025::report::span;
+///
+/// assert_eq!(span([3, 1, 2]), "1..=3");
+/// assert_eq!(span(Vec::<u8>::new()), "none");
+/// ```
+pub fn span<T: Ord + Copy + Display>(values: impl IntoIterator<Item = T>) -> String {
+    let mut values = values.into_iter();
+    let Some(first) = values.next() else {
+        return "none".to_string();
+    };
+    let (low, high) = values.fold((first, first), |(low, high), value| {
+        (low.min(value), high.max(value))
+    });
+    format!("{low}..={high}")
+}
+
+/// Text made fit for a cell of a Markdown table, which a `|` would end and a
+/// line break would cut short.
+///
+/// ```
+/// use aoc_2025::report::cell;
+///
+/// assert_eq!(cell("a | b\r\nc"), "a \\| b<br>c");
+/// ```
+pub fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\r', '\n'], "<br>")
+}
+
+/// Times `f` over `runs` runs, stopping at its first failure.
+fn timed<T>(runs: usize, mut f: impl FnMut() -> T) -> Result<(T, Duration), String> {
+    let mut samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        result = Some(runner::catch(&mut f)?);
+        samples.push(start.elapsed());
+    }
+    Ok((result.unwrap(), perf::median(&mut samples)))
+}
+
+/// Writes up a day's run on an input, timing each stage over `runs` runs.
+pub fn report<I>(day: &Day<I>, path: &Path, runs: usize) -> Result<String, String> {
+    let input = runner::read_input(path, day.trim)?;
+    let mut markdown = format!("# Day {:02}\n\n", day.number);
+    let strategy = match day.strategies {
+        [] => "its only strategy".to_string(),
+        [default, ..] => {
+            let name = config::current()
+                .param::<String>(day.number, "strategy")
+                .unwrap_or_else(|| default.name.to_string());
+            let names: Vec<&str> = day.strategies.iter().map(|s| s.name).collect();
+            format!("strategy `{name}` (of {})", names.join(", "))
+        }
+    };
+    let _ = writeln!(
+        markdown,
+        "Input `{}` solved with {strategy} at revision `{}` on {}.\n",
+        path.display(),
+        perf::revision(),
+        perf::machine()
+    );
+
+    let (parsed, parse_time) = timed(runs, || (day.parse)(input))
+        .map_err(|err| format!("{} does not parse: {err}", path.display()))?;
+    markdown += "## Input\n\n| figure | value |\n|---|---|\n";
+    let figures = [
+        ("lines", input.lines().count().to_string()),
+        ("bytes", input.len().to_string()),
+    ];
+    for (name, value) in figures.into_iter().chain((day.stats)(&parsed)) {
+        let _ = writeln!(markdown, "| {name} | {} |", cell(&value));
+    }
+
+    markdown += "\n## Answers\n\n| stage | answer | expected | median time |\n|---|---|---|---|\n";
+    let _ = writeln!(markdown, "| parse | | | {parse_time:.2?} |");
+    let expected = runner::read_expected(path);
+    for (index, part) in [Some(day.part1), day.part2].into_iter().enumerate() {
+        let Some(part) = part else {
+            let _ = writeln!(markdown, "| part {} | - | | |", index + 1);
+            continue;
+        };
+        let (answer, check, time) = match timed(runs, || part(&parsed)) {
+            Ok((answer, time)) => {
+                let check = match expected
+                    .as_ref()
+                    .and_then(|expected| expected[index].as_ref())
+                {
+                    Some(expected) if *expected == answer => "ok".to_string(),
+                    Some(expected) => format!("{expected} (differs)"),
+                    None => "unchecked".to_string(),
+                };
+                (answer, check, format!("{time:.2?}"))
+            }
+            Err(err) => (format!("failed: {err}"), String::new(), String::new()),
+        };
+        let _ = writeln!(
+            markdown,
+            "| part {} | {} | {} | {time} |",
+            index + 1,
+            cell(&answer),
+            cell(&check)
+        );
+    }
+    let _ = writeln!(markdown, "\nTimes are medians of {runs} run(s).");
+
+    if let Some(drawing) = dashboard::drawing(day.number, path) {
+        markdown += "\n## Drawing\n\n";
+        match drawing {
+            Ok(html) => markdown += &html,
+            Err(err) => markdown += &format!("The input could not be drawn: {err}"),
+        }
+        markdown.push('\n');
+    }
+    Ok(markdown)
+}
@@ -0,0 +1,945 @@
+use crate::{
+    anonymize::{self, Anonymize, Rng},
+    audit,
+    budget::{self, MISSING_PART, Verdict},
+    config::{self, Config},
+    input::{self, Trim},
+    perf::{self, Record},
+    repl::{self, Command},
+    report::{self, Stats},
+    shrink::{self, Structure},
+    stream::Stream,
+    vault::{self, Key},
+};
+use std::{
+    any::Any,
+    env, fs,
+    io::{self, BufReader},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+pub type Part<I> = fn(&I) -> String;
+
+/// The entry points of a daily puzzle, as seen by the runner.
+pub struct Day<I: 'static> {
+    pub number: u8,
+    pub trim: Trim,
+    pub parse: fn(&'static str) -> I,
+    pub part1: Part<I>,
+    pub part2: Option<Part<I>>,
+    pub stream: Option<Stream>,
+    pub commands: &'static [Command<I>],
+    /// How the shrinker takes the inputs apart, from the coarsest pieces to
+    /// the finest.
+    pub shrink: &'static [Structure],
+    /// The interchangeable ways of solving the day, if it has several. The
+    /// first one is the default, which `part1` and `part2` solve with.
+    pub strategies: &'static [Strategy<I>],
+    /// How to scramble an input into a look-alike with the same answers, if
+    /// the day knows how.
+    pub anonymize: Option<Anonymize>,
+    /// The figures the reports give of a parsed input.
+    pub stats: Stats<I>,
+}
+
+/// A named way of solving a day. A part it leaves out is solved as the day
+/// does by default.
+pub struct Strategy<I> {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub part1: Option<Part<I>>,
+    pub part2: Option<Part<I>>,
+}
+
+impl<I> Strategy<I> {
+    pub fn parts(&self, day: &Day<I>) -> [Option<Part<I>>; 2] {
+        [self.part1.or(Some(day.part1)), self.part2.or(day.part2)]
+    }
+}
+
+impl<I> Day<I> {
+    /// The day as solved by the strategy the configuration names, as in
+    /// `day09.strategy = "brute-force"`.
+    pub fn configured(self) -> Result<Self, String> {
+        let Some(name) = config::current().param::<String>(self.number, "strategy") else {
+            return Ok(self);
+        };
+        let Some(strategy) = self
+            .strategies
+            .iter()
+            .find(|strategy| strategy.name == name)
+        else {
+            let names: Vec<&str> = self
+                .strategies
+                .iter()
+                .map(|strategy| strategy.name)
+                .collect();
+            return Err(if names.is_empty() {
+                format!("day{:02} has a single strategy", self.number)
+            } else {
+                format!(
+                    "unknown strategy {name}, day{:02} has {}",
+                    self.number,
+                    names.join(", ")
+                )
+            });
+        };
+        let [part1, part2] = strategy.parts(&self);
+        Ok(Day {
+            part1: part1.unwrap_or(self.part1),
+            part2,
+            ..self
+        })
+    }
+}
+
+/// The binary of a day, built next to the running one.
+pub fn day_exe(day: u8) -> PathBuf {
+    let exe = env::current_exe().expect("cannot locate the running executable");
+    exe.with_file_name(format!("day{day:02}{}", env::consts::EXE_SUFFIX))
+}
+
+pub fn default_input_path(day: u8) -> PathBuf {
+    config::current()
+        .data_dir()
+        .join(format!("day{day:02}.txt"))
+}
+
+/// Reads an input, falling back on its sealed copy when an input of the
+/// data directory is missing.
+///
+/// Only the data directory falls back: the store keeps the sealed copies
+/// by file name alone, so a missing `day01.txt` elsewhere is an error rather
+/// than the sealed copy of the one in the data directory.
+fn read_raw(path: &Path) -> Result<String, String> {
+    let in_data_dir = || {
+        let data_dir = config::current().data_dir();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        dir == data_dir
+            || fs::canonicalize(dir)
+                .is_ok_and(|dir| fs::canonicalize(&data_dir).is_ok_and(|data_dir| dir == data_dir))
+    };
+    match fs::read_to_string(path) {
+        Err(err)
+            if err.kind() == io::ErrorKind::NotFound
+                && in_data_dir()
+                && vault::sealed_path(path).exists() =>
+        {
+            vault::read_sealed(path)
+        }
+        result => result.map_err(|err| format!("cannot read {}: {err}", path.display())),
+    }
+}
+
+pub(crate) fn read_input(path: &Path, trim: Trim) -> Result<&'static str, String> {
+    read_raw(path).map(|raw| &*input::normalize(&raw, trim).leak())
+}
+
+fn usage(day: u8) -> ! {
+    eprintln!("usage: day{day:02} [FILE] [--budget SECONDS]");
+    eprintln!("       day{day:02} batch DIR [--budget SECONDS]");
+    eprintln!("       day{day:02} check-input [FILE]");
+    eprintln!("       day{day:02} stream [FILE|-] [--progress N]");
+    eprintln!("       day{day:02} bench [FILE] [--runs N]");
+    eprintln!("       day{day:02} audit [FILE] [--runs N]");
+    eprintln!("       day{day:02} compare [FILE] [--runs N]");
+    eprintln!("       day{day:02} report [FILE] [--runs N] [--output FILE]");
+    eprintln!("       day{day:02} part 1|2 [FILE]");
+    eprintln!("       day{day:02} repl [FILE] [--script FILE]");
+    eprintln!("       day{day:02} encrypt|decrypt [FILE]");
+    eprintln!("       day{day:02} shrink [FILE]");
+    eprintln!("       day{day:02} anonymize [FILE] [--seed N] [--output FILE]");
+    eprintln!(
+        "every command takes [--strategy NAME] [--config FILE] [--data-dir DIR] [--set KEY=VALUE]..."
+    );
+    process::exit(2)
+}
+
+/// Removes `name` and the value following it from the arguments.
+pub fn take_option<'a>(args: &mut Vec<&'a str>, name: &str) -> Option<&'a str> {
+    let index = args.iter().position(|&arg| arg == name)?;
+    let value = args.get(index + 1).copied();
+    args.drain(index..(index + 2).min(args.len()));
+    value.or(Some(""))
+}
+
+pub fn main<I>(day: Day<I>) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut args: Vec<&str> = args.iter().map(String::as_str).collect();
+    // The strategy goes through the configuration, which children inherit.
+    let strategy = take_option(&mut args, "--strategy")
+        .map(|name| format!("day{:02}.strategy={name}", day.number));
+    if let Some(assignment) = &strategy {
+        args.extend(["--set", assignment]);
+    }
+    if let Err(err) = Config::from_args(&mut args).and_then(config::install) {
+        eprintln!("{err}");
+        process::exit(2);
+    }
+    let day = day.configured().unwrap_or_else(|err| {
+        eprintln!("{err}");
+        process::exit(2);
+    });
+    let budget = take_option(&mut args, "--budget")
+        .map(|seconds| budget::parse_budget(seconds).unwrap_or_else(|| usage(day.number)));
+    let script = take_option(&mut args, "--script").map(Path::new);
+    let seed = take_option(&mut args, "--seed")
+        .map(|seed| seed.parse().unwrap_or_else(|_| usage(day.number)));
+    let output = take_option(&mut args, "--output").map(Path::new);
+    match args.as_slice() {
+        [] => run(&day, &default_input_path(day.number), budget),
+        ["batch", dir] => batch(&day, Path::new(dir), budget),
+        ["repl"] => run_repl(&day, &default_input_path(day.number), script),
+        ["repl", path] => run_repl(&day, Path::new(path), script),
+        ["part", part] => solve_part(&day, part, &default_input_path(day.number)),
+        ["part", part, path] => solve_part(&day, part, Path::new(path)),
+        ["encrypt"] => seal(&default_input_path(day.number), true),
+        ["encrypt", path] => seal(Path::new(path), true),
+        ["decrypt"] => seal(&default_input_path(day.number), false),
+        ["decrypt", path] => seal(Path::new(path), false),
+        ["shrink"] => run_shrink(&day, &default_input_path(day.number)),
+        ["shrink", path] => run_shrink(&day, Path::new(path)),
+        ["anonymize", rest @ ..] if rest.len() <= 1 => match day.anonymize {
+            Some(scramble) => {
+                let path = rest
+                    .first()
+                    .map_or_else(|| default_input_path(day.number), PathBuf::from);
+                run_anonymize(&day, scramble, &path, seed, output)
+            }
+            None => {
+                eprintln!("day{:02} cannot be anonymized", day.number);
+                process::exit(2);
+            }
+        },
+        ["check-input"] => check_input(&day, &default_input_path(day.number)),
+        ["check-input", path] => check_input(&day, Path::new(path)),
+        ["stream", rest @ ..] => match (day.stream, stream_args(rest)) {
+            (Some(stream), Some((path, every))) => run_stream(&day, stream, path, every),
+            (None, _) => {
+                eprintln!("day{:02} cannot be streamed", day.number);
+                process::exit(2);
+            }
+            (_, None) => usage(day.number),
+        },
+        ["bench", rest @ ..] => match bench_args(rest) {
+            Some((path, runs)) => {
+                let path = path.map_or_else(|| default_input_path(day.number), PathBuf::from);
+                bench(&day, &path, runs)
+            }
+            None => usage(day.number),
+        },
+        ["compare", rest @ ..] => match bench_args(rest) {
+            Some((path, runs)) => {
+                let path = path.map_or_else(|| default_input_path(day.number), PathBuf::from);
+                compare(&day, &path, runs)
+            }
+            None => usage(day.number),
+        },
+        ["report", rest @ ..] => match bench_args(rest) {
+            Some((path, runs)) => {
+                let path = path.map_or_else(|| default_input_path(day.number), PathBuf::from);
+                run_report(&day, &path, runs, output)
+            }
+            None => usage(day.number),
+        },
+        ["audit", rest @ ..] => match bench_args(rest) {
+            Some((path, runs)) => {
+                let path = path.map_or_else(|| default_input_path(day.number), PathBuf::from);
+                run_audit(&day, &path, runs)
+            }
+            None => usage(day.number),
+        },
+        [path] if !path.starts_with('-') => run(&day, Path::new(path), budget),
+        _ => usage(day.number),
+    }
+}
+
+fn run<I>(day: &Day<I>, path: &Path, budget: Option<Duration>) {
+    if let Some(budget) = budget {
+        return run_isolated(path, budget);
+    }
+    let input = match read_input(path, day.trim) {
+        Ok(input) => (day.parse)(input),
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let answer1 = (day.part1)(&input);
+    println!("The answer to part 1 is {answer1}");
+    if let Some(part2) = day.part2 {
+        let answer2 = part2(&input);
+        println!("The answer to part 2 is {answer2}");
+    }
+}
+
+/// Runs each part in its own process so that one exceeding its budget can be
+/// reported without giving up on the other.
+fn run_isolated(path: &Path, budget: Duration) {
+    let exe = env::current_exe().expect("cannot locate the running executable");
+    let mut failed = false;
+    for part in 1..=2 {
+        match budget::run_part(&exe, part, Some(path), Some(budget)) {
+            Verdict::Skipped => {}
+            Verdict::Solved(answer, _) => println!("The answer to part {part} is {answer}"),
+            Verdict::Failed(err) => {
+                println!("Part {part} failed: {err}");
+                failed = true;
+            }
+            Verdict::Timeout(budget) => {
+                println!("Part {part}: TIMEOUT after {budget:.2?}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Solves a single part, printing its answer and then its duration in
+/// nanoseconds for [`budget::run_part`] to pick up.
+fn solve_part<I>(day: &Day<I>, part: &str, path: &Path) {
+    let solve = match part {
+        "1" => day.part1,
+        "2" => match day.part2 {
+            Some(part2) => part2,
+            None => process::exit(MISSING_PART),
+        },
+        _ => usage(day.number),
+    };
+    let result = read_input(path, day.trim).and_then(|input| {
+        quietly(|| {
+            catch(|| {
+                let input = (day.parse)(input);
+                let start = Instant::now();
+                let answer = solve(&input);
+                (answer, start.elapsed())
+            })
+        })
+    });
+    match result {
+        Ok((answer, elapsed)) => {
+            println!("{answer}");
+            println!("{}", elapsed.as_nanos());
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn run_repl<I>(day: &Day<I>, path: &Path, script: Option<&Path>) {
+    let result = read_input(path, day.trim).and_then(|input| {
+        let input = (day.parse)(input);
+        repl::session(
+            day.number,
+            &input,
+            day.commands,
+            [Some(day.part1), day.part2],
+            script,
+        )
+    });
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
+
+fn stream_args<'a>(args: &[&'a str]) -> Option<(Option<&'a str>, Option<usize>)> {
+    match args {
+        [] => Some((None, None)),
+        [path] => Some((Some(*path), None)),
+        ["--progress", every] => Some((None, Some(every.parse().ok()?))),
+        [path, "--progress", every] => Some((Some(*path), Some(every.parse().ok()?))),
+        _ => None,
+    }
+    .filter(|(_, every)| *every != Some(0))
+}
+
+fn run_stream<I>(day: &Day<I>, stream: Stream, path: Option<&str>, every: Option<usize>) {
+    let mut report = |number: usize, [answer1, answer2]: [String; 2]| {
+        println!("after {number} lines: part 1 = {answer1}, part 2 = {answer2}");
+    };
+    let progress = every.map(|every| (every, &mut report as &mut dyn FnMut(usize, [String; 2])));
+    let result = match path {
+        Some("-") => stream(&mut io::stdin().lock(), day.trim, progress),
+        _ => {
+            let path = path.map_or_else(|| default_input_path(day.number), PathBuf::from);
+            match fs::File::open(&path) {
+                Ok(file) => stream(&mut BufReader::new(file), day.trim, progress),
+                // A sealed input is opened in memory.
+                Err(_) if !path.exists() => {
+                    read_raw(&path).and_then(|raw| stream(&mut raw.as_bytes(), day.trim, progress))
+                }
+                Err(err) => Err(format!("cannot read {}: {err}", path.display())),
+            }
+        }
+    };
+    match result {
+        Ok([answer1, answer2]) => {
+            println!("The answer to part 1 is {answer1}");
+            println!("The answer to part 2 is {answer2}");
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    }
+}
+
+fn bench_args<'a>(args: &[&'a str]) -> Option<(Option<&'a str>, usize)> {
+    match args {
+        [] => Some((None, 10)),
+        [path] => Some((Some(*path), 10)),
+        ["--runs", runs] => Some((None, runs.parse().ok()?)),
+        [path, "--runs", runs] => Some((Some(*path), runs.parse().ok()?)),
+        _ => None,
+    }
+    .filter(|&(_, runs)| runs > 0)
+}
+
+/// Times the parser and both parts over `runs` runs, then appends their
+/// medians to the performance history.
+fn bench<I>(day: &Day<I>, path: &Path, runs: usize) {
+    let input = match read_input(path, day.trim) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = (day.parse)(input);
+        samples[0].push(start.elapsed());
+        let start = Instant::now();
+        (day.part1)(&parsed);
+        samples[1].push(start.elapsed());
+        if let Some(part2) = day.part2 {
+            let start = Instant::now();
+            part2(&parsed);
+            samples[2].push(start.elapsed());
+        }
+    }
+
+    let timestamp = perf::now();
+    let revision = perf::revision();
+    let machine = perf::machine();
+    let file = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let records: Vec<Record> = ["parse", "part 1", "part 2"]
+        .into_iter()
+        .zip(&mut samples)
+        .filter(|(_, samples)| !samples.is_empty())
+        .map(|(stage, samples)| Record {
+            timestamp,
+            revision: revision.clone(),
+            machine: machine.clone(),
+            day: day.number,
+            input: file.clone(),
+            stage: stage.to_string(),
+            median: perf::median(samples),
+            runs,
+        })
+        .collect();
+    for record in &records {
+        println!(
+            "{:6}  median {:.2?} over {runs} run(s)",
+            record.stage, record.median
+        );
+    }
+    let history = perf::history_path();
+    if let Err(err) = perf::append(&history, &records) {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+    println!("recorded as {revision} in {}", history.display());
+}
+
+/// Solves the input with every strategy of the day, `runs` times each,
+/// then tabulates their median times and whether they agree with the
+/// default one.
+fn compare<I>(day: &Day<I>, path: &Path, runs: usize) {
+    if day.strategies.is_empty() {
+        println!("day{:02} has a single strategy", day.number);
+        return;
+    }
+    let input = match read_input(path, day.trim) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let parsed = (day.parse)(input);
+
+    let mut reference: Option<[String; 2]> = None;
+    let mut disagreements = 0;
+    let rows: Vec<Vec<String>> = quietly(|| {
+        day.strategies
+            .iter()
+            .map(|strategy| {
+                let parts = strategy.parts(day).map(|part| match part {
+                    None => Verdict::Skipped,
+                    Some(part) => {
+                        let mut samples = Vec::new();
+                        let mut verdict = Verdict::Skipped;
+                        for _ in 0..runs {
+                            verdict = timed(|| part(&parsed));
+                            match &verdict {
+                                Verdict::Solved(_, elapsed) => samples.push(*elapsed),
+                                _ => break,
+                            }
+                        }
+                        match verdict {
+                            Verdict::Solved(answer, _) => {
+                                Verdict::Solved(answer, perf::median(&mut samples))
+                            }
+                            verdict => verdict,
+                        }
+                    }
+                });
+                let answers = [0, 1].map(|index| parts[index].answer().to_string());
+                let status = match &reference {
+                    None => "default".to_string(),
+                    Some(reference) => {
+                        let differing: Vec<String> = (1..)
+                            .zip(reference.iter().zip(&answers))
+                            .filter(|(_, (reference, answer))| reference != answer)
+                            .map(|(part, (reference, _))| {
+                                format!("part {part} differs from {reference}")
+                            })
+                            .collect();
+                        if differing.is_empty() {
+                            "agrees".to_string()
+                        } else {
+                            disagreements += 1;
+                            differing.join("; ")
+                        }
+                    }
+                };
+                reference.get_or_insert(answers);
+                vec![
+                    strategy.name.to_string(),
+                    parts[0].answer().to_string(),
+                    parts[0].time(),
+                    parts[1].answer().to_string(),
+                    parts[1].time(),
+                    status,
+                ]
+            })
+            .collect()
+    });
+    print_table(
+        &["strategy", "part 1", "time 1", "part 2", "time 2", "status"],
+        &rows,
+    );
+    if disagreements > 0 {
+        println!("{disagreements} of the strategies disagree with the default");
+        process::exit(1);
+    }
+}
+
+/// Solves the input over and over with differently seeded hashers, failing
+/// if the answers depend on it.
+fn run_audit<I>(day: &Day<I>, path: &Path, runs: usize) {
+    let input = match read_input(path, day.trim) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    if !quietly(|| audit::audit(day, path, input, runs)) {
+        process::exit(1);
+    }
+}
+
+/// Writes up a run of the day on an input, to the standard output unless
+/// given a file.
+fn run_report<I>(day: &Day<I>, path: &Path, runs: usize, output: Option<&Path>) {
+    let markdown = match quietly(|| report::report(day, path, runs)) {
+        Ok(markdown) => markdown,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    match output {
+        None => print!("{markdown}"),
+        Some(output) => {
+            if let Err(err) = fs::write(output, markdown) {
+                eprintln!("cannot write {}: {err}", output.display());
+                process::exit(1);
+            }
+            println!("wrote {}", output.display());
+        }
+    }
+}
+
+/// Shrinks an input on which the day fails to a minimal one failing the
+/// same way, and writes it out as a fixture.
+fn run_shrink<I>(day: &Day<I>, path: &Path) {
+    let raw = match read_raw(path) {
+        Ok(raw) => raw,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let Some(failure) = quietly(|| shrink::failure(day, &raw)) else {
+        println!(
+            "day{:02} parses {} and agrees with itself, nothing to shrink",
+            day.number,
+            path.display()
+        );
+        return;
+    };
+    println!("{failure}");
+    let start = Instant::now();
+    let (minimal, tries) = quietly(|| shrink::shrink(day, &raw, &failure));
+    let fixture = shrink::fixture_path(day.number, &failure, &minimal);
+    let written = fixture
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&fixture, &minimal));
+    if let Err(err) = written {
+        eprintln!("cannot write {}: {err}", fixture.display());
+        process::exit(1);
+    }
+    println!(
+        "shrunk {} line(s) of {} byte(s) to {} line(s) of {} in {tries} tries ({:.2?})",
+        raw.lines().count(),
+        raw.len(),
+        minimal.lines().count(),
+        minimal.len(),
+        start.elapsed()
+    );
+    if minimal.lines().count() <= 20 {
+        print!("{minimal}");
+    }
+    println!("wrote {}", fixture.display());
+}
+
+/// Scrambles an input and writes the scrambled copy out, with its answers,
+/// once it solves to the same answers as the input.
+fn run_anonymize<I>(
+    day: &Day<I>,
+    scramble: Anonymize,
+    path: &Path,
+    seed: Option<u64>,
+    output: Option<&Path>,
+) {
+    let input = match read_input(path, day.trim) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+    });
+    let original = match quietly(|| anonymize::answers(day, input)) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("day{:02} fails on {}: {err}", day.number, path.display());
+            process::exit(1);
+        }
+    };
+    let scrambled = match quietly(|| catch(|| scramble(input, &mut Rng::new(seed)))) {
+        Ok(scrambled) => input::normalize(&scrambled, day.trim),
+        Err(err) => {
+            eprintln!("cannot scramble {}: {err}", path.display());
+            process::exit(1);
+        }
+    };
+    let answers = quietly(|| anonymize::answers(day, scrambled.clone().leak()));
+    if answers.as_ref() != Ok(&original) {
+        println!("seed {seed} changes the answers, nothing written");
+        for (part, before) in original.iter().enumerate() {
+            match &answers {
+                Ok(after) if after[part] == *before => {}
+                Ok(after) => println!("part {}: {before} became {}", part + 1, after[part]),
+                Err(err) => println!("part {}: {before} became a failure: {err}", part + 1),
+            }
+        }
+        process::exit(1);
+    }
+    let path = output.map_or_else(|| anonymize::fixture_path(day.number, seed), PathBuf::from);
+    let expected: String = original
+        .iter()
+        .map(|answer| {
+            if answer == "-" {
+                "\n".to_string()
+            } else {
+                format!("{answer}\n")
+            }
+        })
+        .collect();
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, &scrambled))
+        .and_then(|()| fs::write(path.with_extension("expected"), expected));
+    if let Err(err) = written {
+        eprintln!("cannot write {}: {err}", path.display());
+        process::exit(1);
+    }
+    println!(
+        "seed {seed}: part 1 is {}, part 2 is {}",
+        original[0], original[1]
+    );
+    println!("wrote {} and its answers", path.display());
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+pub(crate) fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message)
+}
+
+/// Runs `f` without printing the messages of the panics it catches. The
+/// hook is process-wide, so this wraps a whole batch of work rather than
+/// each item of it.
+pub(crate) fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+fn timed(f: impl FnOnce() -> String) -> Verdict {
+    let start = Instant::now();
+    match catch(f) {
+        Ok(answer) => Verdict::Solved(answer, start.elapsed()),
+        Err(err) => Verdict::Failed(err),
+    }
+}
+
+struct Row {
+    file: String,
+    parts: [Verdict; 2],
+    status: String,
+    failed: bool,
+}
+
+/// Reads the answers recorded next to an input: `foo.txt` is checked
+/// against the first two lines of `foo.expected`, where a blank line leaves
+/// the corresponding part unchecked.
+pub fn read_expected(path: &Path) -> Option<[Option<String>; 2]> {
+    let expected = read_raw(&path.with_extension("expected")).ok()?;
+    let mut lines = expected.lines().map(|line| {
+        let line = line.trim();
+        (!line.is_empty()).then(|| line.to_string())
+    });
+    Some([lines.next().flatten(), lines.next().flatten()])
+}
+
+fn batch_row<I>(day: &Day<I>, path: &Path, budget: Option<Duration>) -> Row {
+    let file = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    let parts = if let Some(budget) = budget {
+        let exe = env::current_exe().expect("cannot locate the running executable");
+        [1, 2].map(|part| budget::run_part(&exe, part, Some(path), Some(budget)))
+    } else {
+        let input = read_input(path, day.trim).and_then(|input| catch(|| (day.parse)(input)));
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                return Row {
+                    file,
+                    parts: [Verdict::Skipped, Verdict::Skipped],
+                    status: format!("parse error: {err}"),
+                    failed: true,
+                };
+            }
+        };
+        [
+            timed(|| (day.part1)(&input)),
+            day.part2
+                .map_or(Verdict::Skipped, |part2| timed(|| part2(&input))),
+        ]
+    };
+
+    let mut problems = Vec::new();
+    for (index, part) in parts.iter().enumerate() {
+        match part {
+            Verdict::Failed(err) => problems.push(format!("part {} failed: {err}", index + 1)),
+            Verdict::Timeout(budget) => {
+                problems.push(format!("part {} exceeded {budget:.2?}", index + 1))
+            }
+            Verdict::Skipped | Verdict::Solved(..) => {}
+        }
+    }
+    let expected = read_expected(path);
+    if let Some(expected) = &expected {
+        for (index, (part, expected)) in parts.iter().zip(expected).enumerate() {
+            if let (Verdict::Solved(answer, _), Some(expected)) = (part, expected)
+                && answer != expected
+            {
+                problems.push(format!("part {} expected {expected}", index + 1));
+            }
+        }
+    }
+
+    let failed = !problems.is_empty();
+    let status = if failed {
+        problems.join("; ")
+    } else if expected.is_some() {
+        "ok".to_string()
+    } else {
+        "unchecked".to_string()
+    };
+    Row {
+        file,
+        parts,
+        status,
+        failed,
+    }
+}
+
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let print_row = |cells: &mut dyn Iterator<Item = &str>| {
+        let line: Vec<String> = cells
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:width$}"))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+    print_row(&mut header.iter().copied());
+    for row in rows {
+        print_row(&mut row.iter().map(String::as_str));
+    }
+}
+
+fn batch<I>(day: &Day<I>, dir: &Path, budget: Option<Duration>) {
+    let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_none_or(|ext| ext != "expected"))
+            .collect(),
+        Err(err) => {
+            eprintln!("cannot read {}: {err}", dir.display());
+            process::exit(1);
+        }
+    };
+    paths.sort();
+
+    let rows: Vec<Row> = quietly(|| {
+        paths
+            .iter()
+            .map(|path| batch_row(day, path, budget))
+            .collect()
+    });
+
+    let table: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            vec![
+                row.file.clone(),
+                row.parts[0].answer().to_string(),
+                row.parts[0].time(),
+                row.parts[1].answer().to_string(),
+                row.parts[1].time(),
+                row.status.clone(),
+            ]
+        })
+        .collect();
+    print_table(
+        &["file", "part 1", "time 1", "part 2", "time 2", "status"],
+        &table,
+    );
+
+    let failures = rows.iter().filter(|row| row.failed).count();
+    println!("{} file(s), {} failure(s)", rows.len(), failures);
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+fn check_input<I>(day: &Day<I>, path: &Path) {
+    let raw = match read_raw(path) {
+        Ok(raw) => raw,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let anomalies = input::anomalies(&raw);
+    if anomalies.is_empty() {
+        println!("no anomalies found");
+    }
+    for anomaly in &anomalies {
+        println!("warning: {anomaly}");
+    }
+
+    let normalized: &'static str = input::normalize(&raw, day.trim).leak();
+    match quietly(|| catch(|| (day.parse)(normalized))) {
+        Ok(_) => println!("{} line(s) parsed", normalized.lines().count()),
+        Err(err) => {
+            println!("error: the input does not parse: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Seals an input and its expected answers into the store, or restores
+/// them from it.
+pub fn seal(path: &Path, encrypt: bool) {
+    let result = Key::load().and_then(|key| {
+        let expected = path.with_extension("expected");
+        if encrypt {
+            for path in [path, &expected] {
+                if path == expected && !path.exists() {
+                    continue;
+                }
+                let sealed = vault::encrypt(&key, path)?;
+                println!("sealed {} as {}", path.display(), sealed.display());
+            }
+        } else {
+            for path in [path, &expected] {
+                if path == expected && !vault::sealed_path(path).exists() {
+                    continue;
+                }
+                vault::decrypt(&key, path)?;
+                println!("restored {}", path.display());
+            }
+        }
+        Ok(())
+    });
+    if let Err(err) = result {
+        eprintln!("{err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,325 @@
+//! A delta-debugging shrinker: it takes an input on which a day fails and
+//! drops pieces of it for as long as the failure still reproduces, then
+//! keeps the smallest case as a regression fixture.
+//!
+//! The pieces follow the structure of each day's input, from the coarsest
+//! to the finest (whole machines, then single buttons, for instance), so
+//! that every candidate still reads as an input of that day.
+
+use crate::{
+    config,
+    input::{self, Trim},
+    runner::{self, Day},
+};
+use std::{
+    fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::PathBuf,
+};
+
+/// A way to take an input apart into numbered pieces and to put back
+/// together the ones that are kept, the indices of which come sorted.
+pub struct Structure {
+    pub count: fn(&str) -> usize,
+    /// Rebuilds the input from the pieces of the given indices, in order.
+    /// Pieces that make no valid input together can be rebuilt as an empty
+    /// input, which days read as having no items, if they parse it at all.
+    pub keep: fn(&str, &[usize]) -> String,
+}
+
+fn keep_lines(input: &str, kept: &[usize]) -> String {
+    let lines: Vec<&str> = input.lines().collect();
+    kept.iter().map(|&i| format!("{}\n", lines[i])).collect()
+}
+
+/// One piece per line.
+pub const LINES: Structure = Structure {
+    count: |input| input.lines().count(),
+    keep: keep_lines,
+};
+
+fn keep_section_lines(input: &str, kept: &[usize]) -> String {
+    let mut index = 0;
+    let sections: Vec<String> = input::sections(input)
+        .iter()
+        .map(|section| {
+            let lines: Vec<&str> = section
+                .lines()
+                .filter(|_| {
+                    index += 1;
+                    kept.binary_search(&(index - 1)).is_ok()
+                })
+                .collect();
+            lines.join("\n")
+        })
+        .collect();
+    sections.join("\n\n") + "\n"
+}
+
+/// One piece per line of any section, the sections themselves staying.
+pub const SECTION_LINES: Structure = Structure {
+    count: |input| {
+        input::sections(input)
+            .iter()
+            .map(|section| section.lines().count())
+            .sum()
+    },
+    keep: keep_section_lines,
+};
+
+/// How a day fails on an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// A part panics, or streaming fails for part 0.
+    Panic { part: u8, message: String },
+    /// Another way of solving a part, a strategy or streaming, gives
+    /// another answer than the day does.
+    Disagreement {
+        part: u8,
+        solved: String,
+        other: String,
+        by: String,
+    },
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic { part: 0, message } => write!(f, "streaming fails: {message}"),
+            Failure::Panic { part, message } => write!(f, "part {part} panics: {message}"),
+            Failure::Disagreement {
+                part,
+                solved,
+                other,
+                by,
+            } => write!(
+                f,
+                "part {part} is solved as {solved} but as {other} by {by}"
+            ),
+        }
+    }
+}
+
+/// Blurs the numbers of a panic message, which tend to change as the input
+/// shrinks without the failure being any different.
+fn shape(message: &str) -> String {
+    let mut shape = String::new();
+    for c in message.chars() {
+        if !c.is_ascii_digit() {
+            shape.push(c);
+        } else if !shape.ends_with('#') {
+            shape.push('#');
+        }
+    }
+    shape
+}
+
+impl Failure {
+    /// Whether `other` is the same failure, on a possibly smaller input.
+    pub fn reproduced_by(&self, other: &Failure) -> bool {
+        match (self, other) {
+            (
+                Failure::Panic { part, message },
+                Failure::Panic {
+                    part: p,
+                    message: m,
+                },
+            ) => part == p && shape(message) == shape(m),
+            (
+                Failure::Disagreement { part, by, .. },
+                Failure::Disagreement { part: p, by: b, .. },
+            ) => part == p && by == b,
+            _ => false,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Failure::Panic { .. } => "panic",
+            Failure::Disagreement { .. } => "disagreement",
+        }
+    }
+}
+
+/// Runs the day on `text`, returning how it fails if it does. Inputs that do
+/// not even parse count as not failing, so that shrinking keeps to valid
+/// inputs.
+pub fn failure<I>(day: &Day<I>, text: &str) -> Option<Failure> {
+    // The parsers want inputs that live forever, so every candidate leaks;
+    // they shrink fast enough for that not to matter.
+    let input: &'static str = input::normalize(text, day.trim).leak();
+    let parsed = runner::catch(|| (day.parse)(input)).ok()?;
+    let mut solved = Vec::new();
+    for (part, solve) in [(1, Some(day.part1)), (2, day.part2)] {
+        let Some(solve) = solve else { continue };
+        match runner::catch(|| solve(&parsed)) {
+            Ok(answer) => solved.push((part, answer)),
+            Err(message) => return Some(Failure::Panic { part, message }),
+        }
+    }
+    let disagreement = |part: u8, solved: &String, other: &String, by: &str| {
+        (solved != other).then(|| Failure::Disagreement {
+            part,
+            solved: solved.clone(),
+            other: other.clone(),
+            by: by.to_string(),
+        })
+    };
+    for strategy in day.strategies {
+        for ((part, solved), solve) in solved.iter().zip(strategy.parts(day)) {
+            let Some(solve) = solve else { continue };
+            let other = runner::catch(|| solve(&parsed)).map_err(|message| Failure::Panic {
+                part: *part,
+                message,
+            });
+            match other {
+                Ok(other) => {
+                    if let Some(failure) = disagreement(*part, solved, &other, strategy.name) {
+                        return Some(failure);
+                    }
+                }
+                Err(failure) => return Some(failure),
+            }
+        }
+    }
+    let stream = day.stream?;
+    let streamed = match stream(&mut input.as_bytes(), Trim::None, None) {
+        Ok(streamed) => streamed,
+        Err(message) => return Some(Failure::Panic { part: 0, message }),
+    };
+    solved.iter().find_map(|(part, solved)| {
+        disagreement(*part, solved, &streamed[*part as usize - 1], "streaming")
+    })
+}
+
+/// The minimal subset of `0..count` for which `test` holds, as found by
+/// Zeller's ddmin: try halves, then complements, then finer partitions.
+/// Dropping every piece is tried first, which ddmin alone never does.
+fn ddmin(count: usize, test: &mut dyn FnMut(&[usize]) -> bool) -> Vec<usize> {
+    if count > 0 && test(&[]) {
+        return Vec::new();
+    }
+    let mut current: Vec<usize> = (0..count).collect();
+    let mut granularity = 2;
+    while current.len() >= 2 {
+        let size = current.len().div_ceil(granularity);
+        let chunks: Vec<Vec<usize>> = current.chunks(size).map(<[usize]>::to_vec).collect();
+        let complements = (0..chunks.len()).map(|skip| {
+            chunks
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != skip)
+                .flat_map(|(_, chunk)| chunk.iter().copied())
+                .collect::<Vec<_>>()
+        });
+        let reduced = chunks
+            .iter()
+            .find(|chunk| test(chunk))
+            .cloned()
+            .map(|chunk| (chunk, 2))
+            .or_else(|| {
+                complements
+                    .filter(|complement| !complement.is_empty())
+                    .find(|complement| test(complement))
+                    .map(|complement| (complement, (granularity - 1).max(2)))
+            });
+        match reduced {
+            Some((subset, next)) => {
+                current = subset;
+                granularity = next;
+            }
+            None if granularity >= current.len() => break,
+            None => granularity = (granularity * 2).min(current.len()),
+        }
+    }
+    current
+}
+
+/// The smallest input found on which the day still fails as it does on
+/// `text`, with the number of candidates tried.
+pub fn shrink<I>(day: &Day<I>, text: &str, original: &Failure) -> (String, usize) {
+    let mut current = input::normalize(text, day.trim);
+    let mut tries = 0;
+    loop {
+        let before = current.len();
+        for structure in day.shrink {
+            let count = (structure.count)(&current);
+            let kept = ddmin(count, &mut |kept| {
+                tries += 1;
+                let candidate = (structure.keep)(&current, kept);
+                runner::catch(|| failure(day, &candidate))
+                    .ok()
+                    .flatten()
+                    .is_some_and(|failure| original.reproduced_by(&failure))
+            });
+            if kept.len() < count {
+                current = (structure.keep)(&current, &kept);
+            }
+        }
+        if current.len() == before {
+            return (current, tries);
+        }
+    }
+}
+
+/// Where the fixture for a shrunk case goes: a name derived from its
+/// content, in the directory of the day's fixtures.
+pub fn fixture_path(day: u8, failure: &Failure, minimal: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    minimal.hash(&mut hasher);
+    config::current()
+        .fixtures_dir()
+        .join(format!("day{day:02}"))
+        .join(format!(
+            "{}-{:08x}.txt",
+            failure.kind(),
+            hasher.finish() as u32
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs ddmin on a test that holds when `needed` is kept, counting the
+    /// subsets tried.
+    fn minimize(count: usize, needed: &[usize]) -> (Vec<usize>, usize) {
+        let mut tried = 0;
+        let kept = ddmin(count, &mut |subset| {
+            tried += 1;
+            needed.iter().all(|i| subset.contains(i))
+        });
+        (kept, tried)
+    }
+
+    #[test]
+    fn ddmin_finds_the_pieces_the_failure_needs() {
+        assert_eq!(minimize(8, &[]).0, []);
+        assert_eq!(minimize(8, &[5]).0, [5]);
+        assert_eq!(minimize(8, &[0, 7]).0, [0, 7]);
+        assert_eq!(minimize(13, &[1, 2, 6, 11]).0, [1, 2, 6, 11]);
+        assert_eq!(minimize(1, &[0]).0, [0]);
+        assert_eq!(minimize(0, &[]).0, []);
+        let (kept, tried) = minimize(1024, &[300]);
+        assert_eq!(kept, [300]);
+        assert!(tried <= 40, "{tried} subsets tried");
+    }
+
+    #[test]
+    fn ddmin_settles_for_a_one_minimal_subset() {
+        // Either of two pieces is enough, but not both at once: no single
+        // piece can be dropped from what is kept.
+        let kept = ddmin(6, &mut |subset| subset.contains(&1) != subset.contains(&4));
+        assert!(kept == [1] || kept == [4], "{kept:?}");
+    }
+
+    #[test]
+    fn pieces_are_put_back_in_order() {
+        let input = "a\nb\nc\n\nd\ne\n";
+        assert_eq!((LINES.count)(input), 6);
+        assert_eq!((LINES.keep)(input, &[0, 2, 4]), "a\nc\nd\n");
+        assert_eq!((SECTION_LINES.count)(input), 5);
+        assert_eq!((SECTION_LINES.keep)(input, &[1, 3, 4]), "b\n\nd\ne\n");
+        assert_eq!((SECTION_LINES.keep)(input, &[0, 1, 2]), "a\nb\nc\n\n\n");
+    }
+}
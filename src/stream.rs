@@ -0,0 +1,63 @@
+use crate::{
+    input::{self, Trim},
+    runner,
+};
+use std::io::BufRead;
+
+/// A puzzle whose answers fold over the lines of the input one at a time, so
+/// it can be solved in constant memory however long the input is.
+pub trait Streaming: Default {
+    type Line;
+
+    fn parse_line(line: &str) -> Self::Line;
+
+    fn push(&mut self, line: Self::Line);
+
+    fn answers(&self) -> [String; 2];
+}
+
+/// Reports the running answers after every given number of lines.
+pub type Progress<'a> = Option<(usize, &'a mut dyn FnMut(usize, [String; 2]))>;
+
+/// Reads the input line by line, reporting the running answers to `progress`.
+pub type Stream =
+    fn(reader: &mut dyn BufRead, trim: Trim, progress: Progress) -> Result<[String; 2], String>;
+
+pub fn stream<S: Streaming>(
+    reader: &mut dyn BufRead,
+    trim: Trim,
+    mut progress: Progress,
+) -> Result<[String; 2], String> {
+    let mut state = S::default();
+    let mut buffer = String::new();
+    let mut number: usize = 0;
+    runner::quietly(|| {
+        loop {
+            buffer.clear();
+            match reader.read_line(&mut buffer) {
+                Ok(0) => break,
+                Ok(_) => number += 1,
+                Err(err) => return Err(format!("line {}: {err}", number + 1)),
+            }
+            let line = input::normalize_line(&buffer, trim);
+            if line.trim().is_empty() {
+                continue;
+            }
+            // The parser sees a single line, so the position it reports is on
+            // line 1 and only needs the actual line number.
+            runner::catch(|| state.push(S::parse_line(line))).map_err(|err| {
+                match err.strip_prefix("line 1, ") {
+                    Some(err) => format!("line {number}, {err}"),
+                    None => format!("line {number}: {err}"),
+                }
+            })?;
+            if let Some((every, report)) = &mut progress
+                && number.is_multiple_of(*every)
+            {
+                report(number, state.answers());
+            }
+        }
+        Ok(())
+    })?;
+    Ok(state.answers())
+}
@@ -0,0 +1,297 @@
+//! Encrypted copies of the puzzle inputs, which unlike the inputs themselves
+//! can be committed. `data/day01.txt` is sealed as `inputs/day01.txt.enc`,
+//! and the runner opens the sealed copy whenever an input of the data
+//! directory is missing.
+//!
+//! The key is derived from a passphrase read from the `AOC_KEY` variable or,
+//! failing that, from the `.aoc-key` file, with Argon2id and a random salt
+//! kept in the header of each sealed file, so that guessing the passphrase
+//! of a published file is slow and has to be done file by file. Sealing
+//! uses ChaCha20-Poly1305 with a random nonce, the header being
+//! authenticated along with the content. Sealing an unchanged input again
+//! keeps its sealed copy as it is, so that there is no spurious diff.
+//!
+//! ```
+//! use aoc_2025::vault::{Key, open, reseal, seal};
+//!
+//! let key = Key::from_passphrase("correct horse battery staple");
+//! let sealed = seal(&key, b"L68\nL30\n");
+//! assert_eq!(open(&key, &sealed).unwrap(), b"L68\nL30\n");
+//! assert!(open(&Key::from_passphrase("wrong"), &sealed).is_err());
+//! assert_eq!(reseal(&key, &sealed, b"L68\nL30\n"), sealed);
+//! ```
+
+use crate::config;
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    AeadCore, ChaCha20Poly1305, KeyInit,
+    aead::{Aead, OsRng, Payload, rand_core::RngCore},
+};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const KEY_VARIABLE: &str = "AOC_KEY";
+
+const MAGIC: &[u8] = b"AOC-VAULT-3\n";
+/// The Argon2id memory, in KiB, and passes of newly sealed files; each file
+/// records its own, up to bounds that keep a forged header from stalling
+/// the runner.
+const MEMORY: u32 = Params::DEFAULT_M_COST;
+const PASSES: u32 = Params::DEFAULT_T_COST;
+const MAX_MEMORY: u32 = 4 * MEMORY;
+const MAX_PASSES: u32 = 8 * PASSES;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+pub fn keyfile_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc-key")
+}
+
+/// The directory of the sealed inputs, `inputs` unless configured with
+/// `data.store`.
+pub fn store_dir() -> PathBuf {
+    config::current().store_dir()
+}
+
+/// Where the sealed copy of a plain file goes.
+pub fn sealed_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or(path.as_os_str());
+    store_dir().join(format!("{}.enc", name.to_string_lossy()))
+}
+
+/// A passphrase, from which each sealed file derives its own key.
+pub struct Key(Vec<u8>);
+
+impl Key {
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        Key(passphrase.trim().as_bytes().to_vec())
+    }
+
+    /// Reads the passphrase from the environment or the keyfile.
+    pub fn load() -> Result<Self, String> {
+        if let Ok(passphrase) = env::var(KEY_VARIABLE)
+            && !passphrase.trim().is_empty()
+        {
+            return Ok(Key::from_passphrase(&passphrase));
+        }
+        let keyfile = keyfile_path();
+        match fs::read_to_string(&keyfile) {
+            Ok(passphrase) if !passphrase.trim().is_empty() => {
+                Ok(Key::from_passphrase(&passphrase))
+            }
+            _ => Err(format!(
+                "no key to open the sealed inputs: set {KEY_VARIABLE} or write the passphrase to {}",
+                keyfile.display()
+            )),
+        }
+    }
+
+    /// The cipher of the files sealed with this salt and these costs.
+    fn cipher(&self, memory: u32, passes: u32, salt: &[u8]) -> Result<ChaCha20Poly1305, String> {
+        let params = Params::new(memory, passes, 1, Some(32)).map_err(|err| err.to_string())?;
+        let mut key = [0; 32];
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(&self.0, salt, &mut key)
+            .map_err(|err| err.to_string())?;
+        Ok(ChaCha20Poly1305::new(&key.into()))
+    }
+}
+
+/// The parts of a sealed file: its header, made of the costs and the salt
+/// of its key and its nonce, then its encrypted body followed by its tag.
+struct Sealed<'a> {
+    memory: u32,
+    passes: u32,
+    salt: &'a [u8],
+    nonce: &'a [u8],
+    header: &'a [u8],
+    body: &'a [u8],
+}
+
+impl<'a> Sealed<'a> {
+    fn parse(sealed: &'a [u8]) -> Result<Self, String> {
+        let rest = sealed
+            .strip_prefix(MAGIC)
+            .filter(|rest| rest.len() >= 8 + SALT_LEN + NONCE_LEN + TAG_LEN)
+            .ok_or("not a sealed input")?;
+        let (memory, rest) = rest.split_at(4);
+        let (passes, rest) = rest.split_at(4);
+        let (salt, rest) = rest.split_at(SALT_LEN);
+        let (nonce, body) = rest.split_at(NONCE_LEN);
+        let memory = u32::from_be_bytes(memory.try_into().unwrap());
+        let passes = u32::from_be_bytes(passes.try_into().unwrap());
+        if !(Params::MIN_M_COST..=MAX_MEMORY).contains(&memory)
+            || !(1..=MAX_PASSES).contains(&passes)
+        {
+            return Err(format!(
+                "{memory} KiB and {passes} passes are not costs of the key"
+            ));
+        }
+        Ok(Sealed {
+            memory,
+            passes,
+            salt,
+            nonce,
+            header: &sealed[..sealed.len() - body.len()],
+            body,
+        })
+    }
+}
+
+/// Encrypts and authenticates `plain` under a fresh salt and nonce.
+pub fn seal(key: &Key, plain: &[u8]) -> Vec<u8> {
+    seal_with(key, MEMORY, PASSES, plain)
+}
+
+/// Returns the `previous` sealed file when it already holds `plain`, so
+/// that sealing the same content again changes nothing, and [`seal`]s
+/// `plain` afresh otherwise.
+pub fn reseal(key: &Key, previous: &[u8], plain: &[u8]) -> Vec<u8> {
+    match open(key, previous) {
+        Ok(opened) if opened == plain => previous.to_vec(),
+        _ => seal(key, plain),
+    }
+}
+
+fn seal_with(key: &Key, memory: u32, passes: u32, plain: &[u8]) -> Vec<u8> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let header = [
+        MAGIC,
+        &memory.to_be_bytes(),
+        &passes.to_be_bytes(),
+        &salt,
+        &nonce,
+    ]
+    .concat();
+    let body = key
+        .cipher(memory, passes, &salt)
+        .and_then(|cipher| {
+            cipher
+                .encrypt(
+                    &nonce,
+                    Payload {
+                        msg: plain,
+                        aad: &header,
+                    },
+                )
+                .map_err(|err| err.to_string())
+        })
+        .unwrap_or_else(|err| panic!("cannot seal: {err}"));
+    [header, body].concat()
+}
+
+/// Checks and decrypts what [`seal`] produced.
+pub fn open(key: &Key, sealed: &[u8]) -> Result<Vec<u8>, String> {
+    let Sealed {
+        memory,
+        passes,
+        salt,
+        nonce,
+        header,
+        body,
+    } = Sealed::parse(sealed)?;
+    key.cipher(memory, passes, salt)?
+        .decrypt(
+            nonce.into(),
+            Payload {
+                msg: body,
+                aad: header,
+            },
+        )
+        .map_err(|_| "wrong key, or the sealed input is corrupt".to_string())
+}
+
+/// Reads the sealed copy of `path`, for when the plain file is missing.
+pub fn read_sealed(path: &Path) -> Result<String, String> {
+    let sealed_path = sealed_path(path);
+    let sealed = fs::read(&sealed_path)
+        .map_err(|err| format!("cannot read {}: {err}", sealed_path.display()))?;
+    let key =
+        Key::load().map_err(|err| format!("{} is sealed but there is {err}", path.display()))?;
+    let plain = open(&key, &sealed).map_err(|err| format!("{}: {err}", sealed_path.display()))?;
+    String::from_utf8(plain).map_err(|err| format!("{}: {err}", sealed_path.display()))
+}
+
+/// Seals the plain file at `path` into the store, returning the path of the
+/// sealed copy.
+pub fn encrypt(key: &Key, path: &Path) -> Result<PathBuf, String> {
+    let plain = fs::read(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    let sealed_path = sealed_path(path);
+    let sealed = match fs::read(&sealed_path) {
+        Ok(previous) => reseal(key, &previous, &plain),
+        Err(_) => seal(key, &plain),
+    };
+    fs::create_dir_all(store_dir())
+        .and_then(|_| fs::write(&sealed_path, sealed))
+        .map_err(|err| format!("cannot write {}: {err}", sealed_path.display()))?;
+    Ok(sealed_path)
+}
+
+/// Restores the plain file at `path` from its sealed copy.
+pub fn decrypt(key: &Key, path: &Path) -> Result<(), String> {
+    let sealed_path = sealed_path(path);
+    let sealed = fs::read(&sealed_path)
+        .map_err(|err| format!("cannot read {}: {err}", sealed_path.display()))?;
+    let plain = open(key, &sealed).map_err(|err| format!("{}: {err}", sealed_path.display()))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("cannot create {}: {err}", dir.display()))?;
+    }
+    fs::write(path, plain).map_err(|err| format!("cannot write {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The cheapest costs Argon2 takes, to keep the tests fast.
+    fn cheap(key: &Key, plain: &[u8]) -> Vec<u8> {
+        seal_with(key, Params::MIN_M_COST, 1, plain)
+    }
+
+    #[test]
+    fn tampered_files_do_not_open() {
+        let key = Key::from_passphrase("correct horse battery staple");
+        let sealed = cheap(&key, b"L68\n");
+        assert_eq!(open(&key, &sealed).unwrap(), b"L68\n");
+        for i in 0..sealed.len() {
+            let mut tampered = sealed.clone();
+            tampered[i] ^= 1;
+            assert!(open(&key, &tampered).is_err(), "byte {i} was not checked");
+        }
+        assert!(open(&key, &sealed[..sealed.len() - 5]).is_err());
+    }
+
+    #[test]
+    fn resealing_keeps_unchanged_files() {
+        let key = Key::from_passphrase("correct horse battery staple");
+        let sealed = cheap(&key, b"L68\n");
+        assert_ne!(cheap(&key, b"L68\n"), sealed);
+        assert_eq!(reseal(&key, &sealed, b"L68\n"), sealed);
+        let changed = reseal(&key, &sealed, b"L68\nR48\n");
+        assert_eq!(open(&key, &changed).unwrap(), b"L68\nR48\n");
+        let other = Key::from_passphrase("another passphrase");
+        assert_eq!(
+            open(&other, &reseal(&other, &sealed, b"L68\n")).unwrap(),
+            b"L68\n"
+        );
+    }
+
+    #[test]
+    fn costs_are_bounded() {
+        let key = Key::from_passphrase("correct horse battery staple");
+        let mut sealed = cheap(&key, b"L68\n");
+        sealed[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&(MAX_MEMORY + 1).to_be_bytes());
+        assert_eq!(
+            open(&key, &sealed),
+            Err(format!(
+                "{} KiB and 1 passes are not costs of the key",
+                MAX_MEMORY + 1
+            ))
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Watches a day while it is being worked on: the source of the day, its
+//! input and its fixtures are polled, and every change re-runs the examples
+//! and the inputs, with the answers compared to those of the previous run
+//! and to the recorded ones. Plain polling of modification times keeps it
+//! free of any notification service.
+
+use crate::{
+    budget::{self, Verdict},
+    config,
+    runner::{self, day_exe, print_table},
+    vault,
+};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the files are polled.
+const INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification times of the watched files.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub struct Watch {
+    day: u8,
+    budget: Option<Duration>,
+    /// The answers of the previous run, by input.
+    previous: BTreeMap<PathBuf, [String; 2]>,
+}
+
+impl Watch {
+    pub fn new(day: u8, budget: Option<Duration>) -> Self {
+        Self {
+            day,
+            budget,
+            previous: BTreeMap::new(),
+        }
+    }
+
+    fn sources(&self) -> [PathBuf; 2] {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        [
+            src.join(format!("day{:02}.rs", self.day)),
+            src.join("bin").join(format!("day{:02}.rs", self.day)),
+        ]
+    }
+
+    /// The real input, then the fixtures of the day in order.
+    fn inputs(&self) -> Vec<PathBuf> {
+        let fixtures = config::current()
+            .fixtures_dir()
+            .join(format!("day{:02}", self.day));
+        let mut fixtures: Vec<PathBuf> = fs::read_dir(fixtures)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        fixtures.sort();
+        let mut inputs = vec![runner::default_input_path(self.day)];
+        inputs.extend(fixtures);
+        inputs
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        let mut paths: Vec<PathBuf> = self.sources().into();
+        for input in self.inputs() {
+            paths.push(input.with_extension("expected"));
+            paths.push(vault::sealed_path(&input));
+            paths.push(input);
+        }
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+                Some((path, modified))
+            })
+            .collect()
+    }
+
+    /// Runs `cargo` with the profile of the running executable, keeping its
+    /// output unless it fails.
+    fn cargo(&self, args: &[&str]) -> Result<(), String> {
+        let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let mut command = Command::new(&cargo);
+        command
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .stdin(Stdio::null());
+        let exe = day_exe(self.day);
+        if exe.parent().and_then(Path::file_name) == Some("release".as_ref()) {
+            command.arg("--release");
+        }
+        let output = command
+            .output()
+            .map_err(|err| format!("cannot start {cargo}: {err}"))?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        }
+    }
+
+    /// Rebuilds the day and runs its examples, reporting whether both went
+    /// through.
+    fn build(&self) -> bool {
+        let bin = format!("day{:02}", self.day);
+        if let Err(err) = self.cargo(&["build", "--quiet", "--bin", &bin]) {
+            println!("build failed:\n{err}");
+            return false;
+        }
+        match self.cargo(&["test", "--quiet", "--doc", &bin]) {
+            Ok(()) => println!("examples pass"),
+            Err(err) => println!("examples fail:\n{err}"),
+        }
+        true
+    }
+
+    /// Solves every input and prints how the answers moved.
+    fn run(&mut self) {
+        let exe = day_exe(self.day);
+        let mut rows = Vec::new();
+        let mut failures = Vec::new();
+        for input in self.inputs() {
+            if !input.exists() && !vault::sealed_path(&input).exists() {
+                continue;
+            }
+            let file = input.file_name().map_or_else(
+                || input.display().to_string(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+            let parts = [1, 2].map(|part| budget::run_part(&exe, part, Some(&input), self.budget));
+            let answers = [0, 1].map(|index| parts[index].answer().to_string());
+            for (part, verdict) in (1..).zip(&parts) {
+                match verdict {
+                    Verdict::Failed(err) => failures.push(format!("{file} part {part}: {err}")),
+                    Verdict::Timeout(budget) => {
+                        failures.push(format!("{file} part {part}: exceeded {budget:.2?}"))
+                    }
+                    Verdict::Skipped | Verdict::Solved(..) => {}
+                }
+            }
+
+            let changes = match self.previous.get(&input) {
+                None => "new".to_string(),
+                Some(previous) if *previous == answers => "same".to_string(),
+                Some(previous) => differences(previous, &answers, "was"),
+            };
+            let checks = match runner::read_expected(&input) {
+                None => "unchecked".to_string(),
+                Some(expected) => {
+                    let expected = [0, 1].map(|index| {
+                        expected[index]
+                            .clone()
+                            .unwrap_or_else(|| answers[index].clone())
+                    });
+                    if expected == answers {
+                        "ok".to_string()
+                    } else {
+                        differences(&expected, &answers, "expected")
+                    }
+                }
+            };
+            rows.push(vec![
+                file,
+                answers[0].clone(),
+                parts[0].time(),
+                answers[1].clone(),
+                parts[1].time(),
+                changes,
+                checks,
+            ]);
+            self.previous.insert(input, answers);
+        }
+        print_table(
+            &[
+                "file",
+                "part 1",
+                "time 1",
+                "part 2",
+                "time 2",
+                "since last run",
+                "against expected",
+            ],
+            &rows,
+        );
+        for failure in failures {
+            println!("{failure}");
+        }
+    }
+
+    /// Polls the files forever, re-running the day after every change.
+    pub fn watch(&mut self) -> ! {
+        let mut seen = Snapshot::new();
+        let mut broken = false;
+        loop {
+            let snapshot = self.snapshot();
+            if snapshot != seen {
+                let changed: Vec<&Path> = snapshot
+                    .iter()
+                    .filter(|(path, modified)| seen.get(*path) != Some(modified))
+                    .map(|(path, _)| path.as_path())
+                    .chain(
+                        seen.keys()
+                            .map(PathBuf::as_path)
+                            .filter(|path| !snapshot.contains_key(*path)),
+                    )
+                    .collect();
+                let rebuild = seen.is_empty()
+                    || broken
+                    || changed
+                        .iter()
+                        .any(|path| self.sources().iter().any(|source| source == path));
+                println!();
+                if seen.is_empty() {
+                    println!("watching day{:02}, stop with Ctrl-C", self.day);
+                } else {
+                    let names: Vec<String> = changed
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect();
+                    println!("changed: {}", names.join(", "));
+                }
+                broken = rebuild && !self.build();
+                if !broken {
+                    self.run();
+                }
+                seen = snapshot;
+            }
+            thread::sleep(INTERVAL);
+        }
+    }
+}
+
+/// The parts whose answers differ, as `part 1 was 3`.
+fn differences(reference: &[String; 2], answers: &[String; 2], verb: &str) -> String {
+    let differences: Vec<String> = (1..)
+        .zip(reference.iter().zip(answers))
+        .filter(|(_, (reference, answer))| reference != answer)
+        .map(|(part, (reference, _))| format!("part {part} {verb} {reference}"))
+        .collect();
+    differences.join("; ")
+}